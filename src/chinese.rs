@@ -0,0 +1,299 @@
+// The Chinese lunisolar calendar from 1900 to 2100, read from a table of the
+// published calendar rather than computed from the positions of the sun and moon.
+// Months start at the new moon and run for 29 or 30 days, and leap years repeat one
+// month, marked as a leap month.
+
+/// JDN of 1900-01-31, the first day of lunar year 1900.
+const EPOCH: i64 = 2415051;
+
+const FIRST_YEAR: i32 = 1900;
+const LAST_YEAR: i32 = 2100;
+
+/// One entry per lunar year from 1900: bits 15 to 4 are set for the months 1 to 12
+/// that have 30 days, bits 3 to 0 hold the leap month, 0 for none, and bit 16 is set
+/// when the leap month has 30 days.
+static YEAR_INFO: [u32; (LAST_YEAR - FIRST_YEAR + 1) as usize] = [
+    0x04bd8, 0x04ae0, 0x0a570, 0x054d5, 0x0d260, 0x0d950, 0x16554, 0x056a0, 0x09ad0, 0x055d2,
+    0x04ae0, 0x0a5b6, 0x0a4d0, 0x0d250, 0x1d255, 0x0b540, 0x0d6a0, 0x0ada2, 0x095b0, 0x14977,
+    0x04970, 0x0a4b0, 0x0b4b5, 0x06a50, 0x06d40, 0x1ab54, 0x02b60, 0x09570, 0x052f2, 0x04970,
+    0x06566, 0x0d4a0, 0x0ea50, 0x16a95, 0x05ad0, 0x02b60, 0x186e3, 0x092e0, 0x1c8d7, 0x0c950,
+    0x0d4a0, 0x1d8a6, 0x0b550, 0x056a0, 0x1a5b4, 0x025d0, 0x092d0, 0x0d2b2, 0x0a950, 0x0b557,
+    0x06ca0, 0x0b550, 0x15355, 0x04da0, 0x0a5b0, 0x14573, 0x052b0, 0x0a9a8, 0x0e950, 0x06aa0,
+    0x0aea6, 0x0ab50, 0x04b60, 0x0aae4, 0x0a570, 0x05260, 0x0f263, 0x0d950, 0x05b57, 0x056a0,
+    0x096d0, 0x04dd5, 0x04ad0, 0x0a4d0, 0x0d4d4, 0x0d250, 0x0d558, 0x0b540, 0x0b6a0, 0x195a6,
+    0x095b0, 0x049b0, 0x0a974, 0x0a4b0, 0x0b27a, 0x06a50, 0x06d40, 0x0af46, 0x0ab60, 0x09570,
+    0x04af5, 0x04970, 0x064b0, 0x074a3, 0x0ea50, 0x06b58, 0x05ac0, 0x0ab60, 0x096d5, 0x092e0,
+    0x0c960, 0x0d954, 0x0d4a0, 0x0da50, 0x07552, 0x056a0, 0x0abb7, 0x025d0, 0x092d0, 0x0cab5,
+    0x0a950, 0x0b4a0, 0x0baa4, 0x0ad50, 0x055d9, 0x04ba0, 0x0a5b0, 0x15176, 0x052b0, 0x0a930,
+    0x07954, 0x06aa0, 0x0ad50, 0x05b52, 0x04b60, 0x0a6e6, 0x0a4e0, 0x0d260, 0x0ea65, 0x0d530,
+    0x05aa0, 0x076a3, 0x096d0, 0x04afb, 0x04ad0, 0x0a4d0, 0x1d0b6, 0x0d250, 0x0d520, 0x0dd45,
+    0x0b5a0, 0x056d0, 0x055b2, 0x049b0, 0x0a577, 0x0a4b0, 0x0aa50, 0x1b255, 0x06d20, 0x0ada0,
+    0x14b63, 0x09370, 0x049f8, 0x04970, 0x064b0, 0x168a6, 0x0ea50, 0x06b20, 0x1a6c4, 0x0aae0,
+    0x0a2e0, 0x0d2e3, 0x0c960, 0x0d557, 0x0d4a0, 0x0da50, 0x05d55, 0x056a0, 0x0a6d0, 0x055d4,
+    0x052d0, 0x0a9b8, 0x0a950, 0x0b4a0, 0x0b6a6, 0x0ad50, 0x055a0, 0x0aba4, 0x0a5b0, 0x052b0,
+    0x0b273, 0x06930, 0x07337, 0x06aa0, 0x0ad50, 0x14b55, 0x04b60, 0x0a570, 0x054e4, 0x0d160,
+    0x0e968, 0x0d520, 0x0daa0, 0x16aa6, 0x056d0, 0x04ae0, 0x0a9d4, 0x0a2d0, 0x0d150, 0x0f252,
+    0x0d520,
+];
+
+static STEMS: [&str; 10] = [
+    "Jia", "Yi", "Bing", "Ding", "Wu", "Ji", "Geng", "Xin", "Ren", "Gui",
+];
+static CHINESE_STEMS: [&str; 10] = ["甲", "乙", "丙", "丁", "戊", "己", "庚", "辛", "壬", "癸"];
+static BRANCHES: [&str; 12] = [
+    "zi", "chou", "yin", "mao", "chen", "si", "wu", "wei", "shen", "you", "xu", "hai",
+];
+static CHINESE_BRANCHES: [&str; 12] = [
+    "子", "丑", "寅", "卯", "辰", "巳", "午", "未", "申", "酉", "戌", "亥",
+];
+static ANIMALS: [&str; 12] = [
+    "Rat", "Ox", "Tiger", "Rabbit", "Dragon", "Snake", "Horse", "Goat", "Monkey", "Rooster", "Dog",
+    "Pig",
+];
+static CHINESE_ANIMALS: [&str; 12] = [
+    "鼠", "牛", "虎", "兔", "龙", "蛇", "马", "羊", "猴", "鸡", "狗", "猪",
+];
+
+/// A day of the Chinese calendar. The year is numbered like the Gregorian year in
+/// which it starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LunarDate {
+    pub year: i32,
+    pub month: u32,
+    /// whether the month repeats the previous month
+    pub leap: bool,
+    pub day: u32,
+}
+
+impl LunarDate {
+    pub fn new(year: i32, month: u32, leap: bool, day: u32) -> LunarDate {
+        LunarDate {
+            year,
+            month,
+            leap,
+            day,
+        }
+    }
+
+    pub fn to_jdn(self) -> Result<i64, String> {
+        let first = new_year(self.year)
+            .ok_or_else(|| format!("`{}` is outside {} to {}", self, FIRST_YEAR, LAST_YEAR))?;
+        if self.day < 1 || self.day > days_in_month(self.year, self.month, self.leap) {
+            return Err(format!("invalid date `{}`", self));
+        }
+        let days_before_month: i64 = months(self.year)
+            .into_iter()
+            .take_while(|&month| month != (self.month, self.leap))
+            .map(|(month, leap)| days_in_month(self.year, month, leap) as i64)
+            .sum();
+        Ok(first + days_before_month + self.day as i64 - 1)
+    }
+
+    /// The Chinese date of a JDN, or `None` outside the years of the table.
+    pub fn from_jdn(jdn: i64) -> Option<LunarDate> {
+        let mut year = FIRST_YEAR;
+        let mut first = EPOCH;
+        if jdn < first {
+            return None;
+        }
+        while jdn >= first + days_in_year(year) {
+            first += days_in_year(year);
+            year += 1;
+            if year > LAST_YEAR {
+                return None;
+            }
+        }
+        for (month, leap) in months(year) {
+            let days = days_in_month(year, month, leap) as i64;
+            if jdn < first + days {
+                return Some(LunarDate::new(year, month, leap, (jdn - first) as u32 + 1));
+            }
+            first += days;
+        }
+        unreachable!("a year holds every day up to the next new year")
+    }
+
+    /// The traditional festival falling on a day, if any.
+    pub fn festival(self) -> Option<&'static str> {
+        if self.leap {
+            return None;
+        }
+        match (self.month, self.day) {
+            (1, 1) => Some("Spring Festival"),
+            (1, 15) => Some("Lantern Festival"),
+            (2, 2) => Some("Dragon Raising Its Head"),
+            (5, 5) => Some("Dragon Boat Festival"),
+            (7, 7) => Some("Qixi Festival"),
+            (7, 15) => Some("Ghost Festival"),
+            (8, 15) => Some("Mid-Autumn Festival"),
+            (9, 9) => Some("Double Ninth Festival"),
+            (12, 8) => Some("Laba Festival"),
+            (12, day) if day == days_in_month(self.year, 12, false) => Some("New Year's Eve"),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for LunarDate {
+    /// e.g. `2025-06-01`, or `2025-06L-01` in the leap sixth month
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let leap = if self.leap { "L" } else { "" };
+        write!(f, "{}-{:02}{}-{:02}", self.year, self.month, leap, self.day)
+    }
+}
+
+fn year_info(year: i32) -> Option<u32> {
+    if (FIRST_YEAR..=LAST_YEAR).contains(&year) {
+        Some(YEAR_INFO[(year - FIRST_YEAR) as usize])
+    } else {
+        None
+    }
+}
+
+/// The month repeated by a leap month in `year`, if any.
+pub fn leap_month(year: i32) -> Option<u32> {
+    year_info(year)
+        .map(|info| info & 0xf)
+        .filter(|&month| month > 0)
+}
+
+/// The number of days of a month, 0 for months the year does not have.
+pub fn days_in_month(year: i32, month: u32, leap: bool) -> u32 {
+    let info = match year_info(year) {
+        Some(info) if (1..=12).contains(&month) => info,
+        _ => return 0,
+    };
+    let long = if !leap {
+        info & (0x10000 >> month) != 0
+    } else if leap_month(year) == Some(month) {
+        info & 0x10000 != 0
+    } else {
+        return 0;
+    };
+    if long {
+        30
+    } else {
+        29
+    }
+}
+
+/// The months of `year` in order, as the month and whether it is a leap month.
+pub fn months(year: i32) -> Vec<(u32, bool)> {
+    let mut months = Vec::with_capacity(13);
+    for month in 1..=12 {
+        months.push((month, false));
+        if leap_month(year) == Some(month) {
+            months.push((month, true));
+        }
+    }
+    months
+}
+
+fn days_in_year(year: i32) -> i64 {
+    months(year)
+        .into_iter()
+        .map(|(month, leap)| days_in_month(year, month, leap) as i64)
+        .sum()
+}
+
+/// The JDN of the lunar new year, the first day of the first month, of `year`.
+pub fn new_year(year: i32) -> Option<i64> {
+    if !(FIRST_YEAR..=LAST_YEAR).contains(&year) {
+        return None;
+    }
+    Some(EPOCH + (FIRST_YEAR..year).map(days_in_year).sum::<i64>())
+}
+
+/// The sexagenary name and zodiac animal of `year`, e.g. `Yisi (Snake)`, or `乙巳年 (蛇)`
+/// for Chinese locales.
+pub fn year_name(year: i32, locale: &str) -> String {
+    // 4 AD was the first year, jiazi, of a cycle
+    let stem = (year - 4).rem_euclid(10) as usize;
+    let branch = (year - 4).rem_euclid(12) as usize;
+    if locale.starts_with("zh") {
+        format!(
+            "{}{}年 ({})",
+            CHINESE_STEMS[stem], CHINESE_BRANCHES[branch], CHINESE_ANIMALS[branch]
+        )
+    } else {
+        format!("{}{} ({})", STEMS[stem], BRANCHES[branch], ANIMALS[branch])
+    }
+}
+
+#[test]
+fn test_lunar_new_years() {
+    let test_cases = [
+        (1900, 2415051),
+        // 1950-02-17
+        (1950, 2433330),
+        // 2000-02-05
+        (2000, 2451580),
+        // 2025-01-29
+        (2025, 2460705),
+        // 2026-02-17
+        (2026, 2461089),
+        // 2100-02-09
+        (2100, 2488109),
+    ];
+    for (year, jdn) in test_cases.iter() {
+        assert_eq!(new_year(*year), Some(*jdn), "{}", year);
+        assert_eq!(
+            LunarDate::from_jdn(*jdn),
+            Some(LunarDate::new(*year, 1, false, 1))
+        );
+    }
+    assert_eq!(new_year(1899), None);
+    assert_eq!(LunarDate::from_jdn(EPOCH - 1), None);
+}
+
+#[test]
+fn test_lunar_leap_months() {
+    assert_eq!(leap_month(2025), Some(6));
+    assert_eq!(leap_month(2023), Some(2));
+    assert_eq!(leap_month(2024), None);
+    assert_eq!(months(2025).len(), 13);
+    assert_eq!(months(2025)[6], (6, true));
+    assert_eq!(days_in_month(2025, 7, true), 0);
+    for year in FIRST_YEAR..=LAST_YEAR {
+        assert!(
+            matches!(days_in_year(year), 353..=355 | 383..=385),
+            "{}",
+            year
+        );
+    }
+}
+
+#[test]
+fn test_lunar_conversions() {
+    // Mid-Autumn Festival 2025, 2025-10-06
+    assert_eq!(LunarDate::new(2025, 8, false, 15).to_jdn(), Ok(2460955));
+    assert_eq!(
+        LunarDate::from_jdn(2460955),
+        Some(LunarDate::new(2025, 8, false, 15))
+    );
+    // the leap sixth month of 2025 started on 2025-07-25
+    assert_eq!(
+        LunarDate::from_jdn(2460882),
+        Some(LunarDate::new(2025, 6, true, 1))
+    );
+    assert!(LunarDate::new(2024, 6, true, 1).to_jdn().is_err());
+    for jdn in (EPOCH..EPOCH + 73000).step_by(97) {
+        assert_eq!(LunarDate::from_jdn(jdn).unwrap().to_jdn(), Ok(jdn));
+    }
+}
+
+#[test]
+fn test_lunar_year_names_and_festivals() {
+    assert_eq!(year_name(2025, "en_US"), "Yisi (Snake)");
+    assert_eq!(year_name(2024, "zh_CN"), "甲辰年 (龙)");
+    assert_eq!(year_name(1984, "en_US"), "Jiazi (Rat)");
+    assert_eq!(
+        LunarDate::new(2025, 8, false, 15).festival(),
+        Some("Mid-Autumn Festival")
+    );
+    assert_eq!(LunarDate::new(2025, 6, true, 5).festival(), None);
+    assert_eq!(
+        LunarDate::new(2024, 12, false, 29).festival(),
+        Some("New Year's Eve")
+    );
+}
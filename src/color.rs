@@ -0,0 +1,85 @@
+use std::env;
+use std::io::IsTerminal;
+use std::str::FromStr;
+
+/// When to emit ANSI colour codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorChoice {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    /// Resolves the choice against the environment and stdout.
+    ///
+    /// `auto` honours `NO_COLOR` and `CLICOLOR_FORCE` and otherwise colours
+    /// only when stdout is a terminal.
+    pub fn enabled(self) -> bool {
+        resolve(
+            self,
+            env::var("NO_COLOR").ok().as_deref(),
+            env::var("CLICOLOR_FORCE").ok().as_deref(),
+            std::io::stdout().is_terminal(),
+        )
+    }
+}
+
+impl FromStr for ColorChoice {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(ColorChoice::Auto),
+            "always" => Ok(ColorChoice::Always),
+            "never" => Ok(ColorChoice::Never),
+            _ => Err(format!(
+                "invalid color `{}`, expected auto, always or never",
+                s
+            )),
+        }
+    }
+}
+
+fn resolve(
+    choice: ColorChoice,
+    no_color: Option<&str>,
+    clicolor_force: Option<&str>,
+    is_terminal: bool,
+) -> bool {
+    match choice {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => {
+            if no_color.is_some_and(|v| !v.is_empty()) {
+                false
+            } else if clicolor_force.is_some_and(|v| !v.is_empty() && v != "0") {
+                true
+            } else {
+                is_terminal
+            }
+        }
+    }
+}
+
+#[test]
+fn test_parse_color_choice() {
+    assert_eq!("auto".parse(), Ok(ColorChoice::Auto));
+    assert_eq!("always".parse(), Ok(ColorChoice::Always));
+    assert_eq!("never".parse(), Ok(ColorChoice::Never));
+    assert!("sometimes".parse::<ColorChoice>().is_err());
+}
+
+#[test]
+fn test_resolve_color_choice() {
+    assert!(resolve(ColorChoice::Always, Some("1"), None, false));
+    assert!(!resolve(ColorChoice::Never, None, Some("1"), true));
+    assert!(resolve(ColorChoice::Auto, None, None, true));
+    assert!(!resolve(ColorChoice::Auto, None, None, false));
+    assert!(!resolve(ColorChoice::Auto, Some("1"), None, true));
+    assert!(!resolve(ColorChoice::Auto, Some("1"), Some("1"), true));
+    assert!(resolve(ColorChoice::Auto, Some(""), None, true));
+    assert!(resolve(ColorChoice::Auto, None, Some("1"), false));
+    assert!(!resolve(ColorChoice::Auto, None, Some("0"), false));
+}
@@ -0,0 +1,135 @@
+// The Coptic and Ethiopian calendars, which share their structure: twelve months of
+// 30 days followed by a 13th month of 5 days, or 6 in the year before a Julian leap
+// year. They only differ in the era years are counted from.
+
+use crate::jdn::Ymd;
+
+/// JDN of 1 Thout AM 1, 284-08-29 Julian, the start of the Era of Martyrs.
+pub const COPTIC_EPOCH: i64 = 1825030;
+
+/// JDN of 1 Meskerem 1 of the Amete Mihret (Era of Incarnation), 8-08-29 Julian.
+pub const ETHIOPIAN_EPOCH: i64 = 1724221;
+
+static COPTIC_MONTH_NAMES: [&str; 13] = [
+    "Thout", "Paopi", "Hathor", "Koiak", "Tobi", "Meshir", "Paremhat", "Parmouti", "Pashons",
+    "Paoni", "Epip", "Mesori", "Nesi",
+];
+
+static ETHIOPIAN_MONTH_NAMES: [&str; 13] = [
+    "Meskerem", "Tikimt", "Hidar", "Tahsas", "Tir", "Yekatit", "Megabit", "Miyazya", "Ginbot",
+    "Sene", "Hamle", "Nehase", "Pagume",
+];
+
+static AMHARIC_MONTH_NAMES: [&str; 13] = [
+    "መስከረም",
+    "ጥቅምት",
+    "ኅዳር",
+    "ታኅሣሥ",
+    "ጥር",
+    "የካቲት",
+    "መጋቢት",
+    "ሚያዝያ",
+    "ግንቦት",
+    "ሰኔ",
+    "ሐምሌ",
+    "ነሐሴ",
+    "ጳጉሜን",
+];
+
+pub fn is_leap_year(year: i64) -> bool {
+    year.rem_euclid(4) == 3
+}
+
+pub fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        1..=12 => 30,
+        13 if is_leap_year(year) => 6,
+        13 => 5,
+        _ => 0,
+    }
+}
+
+/// The name of a Coptic month.
+pub fn coptic_month_name(month: u32) -> String {
+    COPTIC_MONTH_NAMES[(month as usize - 1) % 13].to_string()
+}
+
+/// The name of an Ethiopian month, in Ge'ez script for Amharic and Tigrinya locales.
+pub fn ethiopian_month_name(month: u32, locale: &str) -> String {
+    let names = if locale.starts_with("am") || locale.starts_with("ti") {
+        &AMHARIC_MONTH_NAMES
+    } else {
+        &ETHIOPIAN_MONTH_NAMES
+    };
+    names[(month as usize - 1) % 13].to_string()
+}
+
+/// Converts a date counted from `epoch` to its JDN.
+pub fn to_jdn(date: Ymd, epoch: i64) -> Result<i64, String> {
+    if date.month < 1 || date.month > 13 {
+        return Err(format!("invalid month in `{}`", date));
+    }
+    if date.day < 1 || date.day > days_in_month(date.year, date.month) {
+        return Err(format!("invalid day in `{}`", date));
+    }
+    Ok(epoch - 1
+        + 365 * (date.year - 1)
+        + date.year.div_euclid(4)
+        + 30 * (date.month as i64 - 1)
+        + date.day as i64)
+}
+
+/// Converts a JDN to a date counted from `epoch`.
+pub fn from_jdn(jdn: i64, epoch: i64) -> Ymd {
+    let year = (4 * (jdn - epoch) + 1463).div_euclid(1461);
+    let new_year = epoch - 1 + 365 * (year - 1) + year.div_euclid(4) + 1;
+    let days = (jdn - new_year) as u32;
+    Ymd::new(year, days / 30 + 1, days % 30 + 1)
+}
+
+#[test]
+fn test_coptic_leap_years() {
+    assert!(is_leap_year(2015));
+    assert!(!is_leap_year(2016));
+    assert_eq!(days_in_month(2015, 13), 6);
+    assert_eq!(days_in_month(2017, 13), 5);
+    assert_eq!(days_in_month(2017, 14), 0);
+}
+
+#[test]
+fn test_coptic_conversions() {
+    let test_cases = [
+        // Enkutatash, the Ethiopian new year 2018, 2025-09-11
+        (Ymd::new(2018, 1, 1), ETHIOPIAN_EPOCH, 2460930),
+        // Ethiopian Christmas, 29 Tahsas 2017, 2025-01-07
+        (Ymd::new(2017, 4, 29), ETHIOPIAN_EPOCH, 2460683),
+        // 6 Pagume 2015, 2023-09-11
+        (Ymd::new(2015, 13, 6), ETHIOPIAN_EPOCH, 2460199),
+        // Nayrouz, the Coptic new year 1742, 2025-09-11
+        (Ymd::new(1742, 1, 1), COPTIC_EPOCH, 2460930),
+        (Ymd::new(1, 1, 1), COPTIC_EPOCH, COPTIC_EPOCH),
+    ];
+    for (date, epoch, jdn) in test_cases.iter() {
+        assert_eq!(to_jdn(*date, *epoch), Ok(*jdn), "{}", date);
+        assert_eq!(from_jdn(*jdn, *epoch), *date, "{}", jdn);
+    }
+    assert!(to_jdn(Ymd::new(2017, 13, 6), ETHIOPIAN_EPOCH).is_err());
+}
+
+#[test]
+fn test_coptic_round_trip() {
+    for jdn in (1000000..2600000).step_by(101) {
+        assert_eq!(to_jdn(from_jdn(jdn, COPTIC_EPOCH), COPTIC_EPOCH), Ok(jdn));
+        assert_eq!(
+            to_jdn(from_jdn(jdn, ETHIOPIAN_EPOCH), ETHIOPIAN_EPOCH),
+            Ok(jdn)
+        );
+    }
+}
+
+#[test]
+fn test_coptic_month_names() {
+    assert_eq!(ethiopian_month_name(1, "en_US"), "Meskerem");
+    assert_eq!(ethiopian_month_name(13, "am_ET"), "ጳጉሜን");
+    assert_eq!(coptic_month_name(13), "Nesi");
+}
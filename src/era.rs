@@ -0,0 +1,204 @@
+// Japanese era (wareki) names, read from the ERA definitions of the ja_JP locale,
+// which follow the POSIX format `direction:offset:start:end:name:format`.
+
+use chrono::{Datelike, NaiveDate};
+use pure_rust_locales::ja_JP::LC_TIME;
+
+/// The romanized names of the eras since the adoption of the Gregorian calendar.
+static ROMANIZED_NAMES: [(&str, &str); 5] = [
+    ("明治", "Meiji"),
+    ("大正", "Taisho"),
+    ("昭和", "Showa"),
+    ("平成", "Heisei"),
+    ("令和", "Reiwa"),
+];
+
+/// A span of days sharing an era name, e.g. Reiwa 2 onwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Era {
+    start: NaiveDate,
+    /// the last day, or `None` for an era without end
+    end: Option<NaiveDate>,
+    /// the year number of `start`
+    offset: i32,
+    /// whether the era runs back in time from `start`, as for years before the common era
+    backwards: bool,
+    name: &'static str,
+    /// the year as a `strftime` like format, e.g. `%EC%Ey年`
+    format: &'static str,
+}
+
+impl Era {
+    fn contains(self, date: NaiveDate) -> bool {
+        if self.backwards {
+            date <= self.start && self.end.map_or(true, |end| date >= end)
+        } else {
+            date >= self.start && self.end.map_or(true, |end| date <= end)
+        }
+    }
+
+    /// The year of `date` in the era, e.g. `令和7年`, or `令和元年` for its first year, or
+    /// `Reiwa 7` outside Japanese locales, where only the modern eras are named.
+    fn year_name(self, date: NaiveDate, locale: &str) -> Option<String> {
+        let year = if self.backwards {
+            self.offset + self.start.year() - date.year()
+        } else {
+            self.offset + date.year() - self.start.year()
+        };
+        if locale.starts_with("ja") {
+            return Some(
+                self.format
+                    .replace("%EC", self.name)
+                    .replace("%Ey", &year.to_string()),
+            );
+        }
+        ROMANIZED_NAMES
+            .iter()
+            .find(|(name, _)| *name == self.name)
+            .map(|(_, romanized)| format!("{} {}", romanized, year))
+    }
+}
+
+fn parse_date(s: &str) -> Option<NaiveDate> {
+    let mut parts = s.splitn(3, '/');
+    let year: i32 = parts.next()?.parse().ok()?;
+    // the locale counts years without a year 0, so -1 is 1 BC
+    let year = if year < 0 { year + 1 } else { year };
+    let month = parts.next()?.parse().ok()?;
+    let day = parts.next()?.parse().ok()?;
+    NaiveDate::from_ymd_opt(year, month, day)
+}
+
+fn parse_era(s: &'static str) -> Option<Era> {
+    let mut fields = s.splitn(6, ':');
+    // the direction of year numbering, which is `+` for every era of ja_JP
+    fields.next()?;
+    let offset = fields.next()?.parse().ok()?;
+    let start = parse_date(fields.next()?)?;
+    let (end, backwards) = match fields.next()? {
+        "+*" => (None, false),
+        "-*" => (None, true),
+        end => {
+            let end = parse_date(end)?;
+            (Some(end), end < start)
+        }
+    };
+    Some(Era {
+        start,
+        end,
+        offset,
+        backwards,
+        name: fields.next()?,
+        format: fields.next()?,
+    })
+}
+
+fn eras() -> Vec<Era> {
+    LC_TIME::ERA
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|era| parse_era(era))
+        .collect()
+}
+
+fn era_of(date: NaiveDate) -> Option<Era> {
+    eras().into_iter().find(|era| era.contains(date))
+}
+
+/// The era year of a date, e.g. `令和7年`, or `Reiwa 7` outside Japanese locales.
+pub fn year_name(date: NaiveDate, locale: &str) -> Option<String> {
+    era_of(date)?.year_name(date, locale)
+}
+
+/// The era years a calendar year overlaps, e.g. `平成31年` and `令和元年` for 2019.
+pub fn year_names(year: i32, locale: &str) -> Vec<String> {
+    match (
+        NaiveDate::from_ymd_opt(year, 1, 1),
+        NaiveDate::from_ymd_opt(year, 12, 31),
+    ) {
+        (Some(first), Some(last)) => range_names(first, last, locale),
+        _ => Vec::new(),
+    }
+}
+
+/// The era years the days from `first` to `last` fall in, e.g. `昭和64年` and `平成元年`
+/// for January 1989.
+pub fn range_names(first: NaiveDate, last: NaiveDate, locale: &str) -> Vec<String> {
+    let mut days: Vec<NaiveDate> = eras()
+        .into_iter()
+        .filter(|era| !era.backwards && first < era.start && era.start <= last)
+        .map(|era| era.start)
+        .chain(Some(first))
+        .collect();
+    days.sort();
+    let mut names: Vec<String> = Vec::new();
+    for name in days.into_iter().filter_map(|day| year_name(day, locale)) {
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+/// A date in the era format of the ja_JP locale, e.g. `令和7年03月14日`.
+pub fn date_name(date: NaiveDate) -> Option<String> {
+    let year = year_name(date, "ja_JP")?;
+    Some(
+        LC_TIME::ERA_D_FMT?
+            .replace("%EY", &year)
+            .replace("%m", &format!("{:02}", date.month()))
+            .replace("%d", &format!("{:02}", date.day())),
+    )
+}
+
+#[test]
+fn test_era_year_names() {
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let year_name = |date| year_name(date, "ja_JP").unwrap();
+    assert_eq!(year_name(date(2025, 3, 14)), "令和7年");
+    assert_eq!(year_name(date(2019, 4, 30)), "平成31年");
+    assert_eq!(year_name(date(2019, 5, 1)), "令和元年");
+    assert_eq!(year_name(date(1989, 1, 7)), "昭和64年");
+    assert_eq!(year_name(date(1989, 1, 8)), "平成元年");
+    assert_eq!(year_name(date(1912, 7, 29)), "明治45年");
+    assert_eq!(year_name(date(1872, 12, 31)), "西暦1872年");
+    assert_eq!(year_name(date(0, 12, 31)), "紀元前1年");
+}
+
+#[test]
+fn test_romanized_era_year_names() {
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    assert_eq!(year_name(date(2019, 5, 1), "en_US").unwrap(), "Reiwa 1");
+    assert_eq!(year_name(date(1926, 12, 24), "").unwrap(), "Taisho 15");
+    assert_eq!(year_name(date(1872, 12, 31), "en_US"), None);
+}
+
+#[test]
+fn test_era_year_names_of_a_year() {
+    assert_eq!(year_names(2025, "ja_JP"), ["令和7年"]);
+    assert_eq!(year_names(2019, "ja_JP"), ["平成31年", "令和元年"]);
+    assert_eq!(year_names(1989, "en_US"), ["Showa 64", "Heisei 1"]);
+}
+
+#[test]
+fn test_era_year_names_of_a_range() {
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    assert_eq!(
+        range_names(date(1989, 1, 1), date(1989, 1, 31), "en_US"),
+        ["Showa 64", "Heisei 1"]
+    );
+    assert_eq!(
+        range_names(date(1926, 12, 1), date(1926, 12, 31), "en_US"),
+        ["Taisho 15", "Showa 1"]
+    );
+    assert_eq!(
+        range_names(date(1989, 2, 1), date(1989, 2, 28), "en_US"),
+        ["Heisei 1"]
+    );
+}
+
+#[test]
+fn test_era_date_name() {
+    let date = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+    assert_eq!(date_name(date).unwrap(), "令和7年03月14日");
+}
@@ -0,0 +1,197 @@
+use crate::year_numbering::YearNumbering;
+use chrono::{Datelike, Duration, NaiveDate};
+use std::str::FromStr;
+
+static MONTH_NAMES: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+/// Which calendar year names a fiscal year that spans two of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FiscalLabel {
+    /// the year it starts in, e.g. `FY2025`
+    Start,
+    /// the year it ends in, e.g. `FY2026`, as for the US federal fiscal year
+    End,
+    /// both years, e.g. `FY2025/26`
+    #[default]
+    Span,
+}
+
+impl FromStr for FiscalLabel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "start" => Ok(FiscalLabel::Start),
+            "end" => Ok(FiscalLabel::End),
+            "span" => Ok(FiscalLabel::Span),
+            _ => Err(format!(
+                "invalid fiscal label `{}`, expected start, end or span",
+                s
+            )),
+        }
+    }
+}
+
+/// A twelve month year starting at `start_month`, e.g. April for the UK or Japan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FiscalYear {
+    pub start_month: u32,
+    pub label: FiscalLabel,
+}
+
+impl FiscalYear {
+    pub fn new(start_month: u32, label: FiscalLabel) -> FiscalYear {
+        FiscalYear { start_month, label }
+    }
+
+    /// The calendar year in which the fiscal year named `year` starts.
+    pub fn start_year(self, year: u32) -> u32 {
+        if self.label == FiscalLabel::End && self.start_month > 1 {
+            year.saturating_sub(1)
+        } else {
+            year
+        }
+    }
+
+    /// The calendar year and month of each month of the fiscal year named `year`.
+    pub fn months(self, year: u32) -> Vec<(u32, usize)> {
+        let start_year = self.start_year(year);
+        (0..12)
+            .map(|i| {
+                let month = self.start_month - 1 + i;
+                (start_year + month / 12, (month % 12 + 1) as usize)
+            })
+            .collect()
+    }
+
+    /// The name of the fiscal year named `year`, e.g. `FY2025/26`, with its years
+    /// numbered by `numbering`, e.g. `FY2568/69`.
+    pub fn name(self, year: u32, numbering: YearNumbering) -> String {
+        let start_year = numbering.number(self.start_year(year) as i64);
+        if self.start_month == 1 {
+            return format!("FY{}", start_year);
+        }
+        match self.label {
+            FiscalLabel::Start => format!("FY{}", start_year),
+            FiscalLabel::End => format!("FY{}", start_year + 1),
+            FiscalLabel::Span => {
+                format!("FY{}/{:02}", start_year, (start_year + 1).rem_euclid(100))
+            }
+        }
+    }
+
+    /// The name, as a year, of the fiscal year holding `date`.
+    pub fn year_of(self, date: NaiveDate) -> u32 {
+        let start_year = self.first_day(date).year() as u32;
+        if self.label == FiscalLabel::End && self.start_month > 1 {
+            start_year + 1
+        } else {
+            start_year
+        }
+    }
+
+    /// The fiscal quarter and week of a date. Week 1 is the week holding the first day
+    /// of the fiscal year, with weeks starting on `starting_day` (0 is Sunday).
+    pub fn week_of(self, date: NaiveDate, starting_day: u32) -> (u32, u32) {
+        let quarter = (date.month() + 12 - self.start_month) % 12 / 3 + 1;
+        let first_day = self.first_day(date);
+        let offset = (first_day.weekday().num_days_from_sunday() + 7 - starting_day % 7) % 7;
+        let days = (date - first_day).num_days() + offset as i64;
+        (quarter, days as u32 / 7 + 1)
+    }
+
+    /// The first day of the fiscal year holding `date`.
+    fn first_day(self, date: NaiveDate) -> NaiveDate {
+        let year = if date.month() >= self.start_month {
+            date.year()
+        } else {
+            date.year() - 1
+        };
+        NaiveDate::from_ymd_opt(year, self.start_month, 1)
+            .unwrap_or(date - Duration::days(date.ordinal0() as i64))
+    }
+}
+
+/// Parses an English month name of at least 3 letters or a month number.
+pub fn parse_month(s: &str) -> Result<u32, String> {
+    let month = s.trim().to_lowercase();
+    month
+        .parse::<u32>()
+        .ok()
+        .filter(|m| (1..=12).contains(m))
+        .or_else(|| {
+            MONTH_NAMES
+                .iter()
+                .position(|name| month.len() >= 3 && name.starts_with(&month))
+                .map(|m| m as u32 + 1)
+        })
+        .ok_or_else(|| format!("invalid month `{}`", s.trim()))
+}
+
+#[test]
+fn test_parse_month() {
+    assert_eq!(parse_month("april"), Ok(4));
+    assert_eq!(parse_month("Oct"), Ok(10));
+    assert_eq!(parse_month("7"), Ok(7));
+    assert!(parse_month("ju").is_err());
+    assert!(parse_month("13").is_err());
+}
+
+#[test]
+fn test_fiscal_months_and_names() {
+    let april = FiscalYear::new(4, FiscalLabel::Span);
+    let months = april.months(2025);
+    assert_eq!(months[0], (2025, 4));
+    assert_eq!(months[8], (2025, 12));
+    assert_eq!(months[9], (2026, 1));
+    assert_eq!(months[11], (2026, 3));
+    assert_eq!(april.name(2025, YearNumbering::Common), "FY2025/26");
+    assert_eq!(april.name(2099, YearNumbering::Common), "FY2099/00");
+    assert_eq!(april.name(2025, YearNumbering::Buddhist), "FY2568/69");
+
+    let october = FiscalYear::new(10, FiscalLabel::End);
+    assert_eq!(october.months(2026)[0], (2025, 10));
+    assert_eq!(october.name(2026, YearNumbering::Common), "FY2026");
+    assert_eq!(october.name(2026, YearNumbering::Minguo), "FY115");
+    assert_eq!(
+        FiscalYear::new(1, FiscalLabel::Span).name(2025, YearNumbering::Common),
+        "FY2025"
+    );
+}
+
+#[test]
+fn test_fiscal_year_of() {
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let april = FiscalYear::new(4, FiscalLabel::Span);
+    assert_eq!(april.year_of(date(2026, 3, 31)), 2025);
+    assert_eq!(april.year_of(date(2026, 4, 1)), 2026);
+    let october = FiscalYear::new(10, FiscalLabel::End);
+    assert_eq!(october.year_of(date(2025, 10, 1)), 2026);
+    assert_eq!(october.year_of(date(2025, 9, 30)), 2025);
+}
+
+#[test]
+fn test_fiscal_week_of() {
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let april = FiscalYear::new(4, FiscalLabel::Span);
+    // 2025-04-01 is a Tuesday
+    assert_eq!(april.week_of(date(2025, 4, 1), 0), (1, 1));
+    assert_eq!(april.week_of(date(2025, 4, 5), 0), (1, 1));
+    assert_eq!(april.week_of(date(2025, 4, 6), 0), (1, 2));
+    assert_eq!(april.week_of(date(2025, 4, 6), 1), (1, 1));
+    assert_eq!(april.week_of(date(2025, 7, 1), 0), (2, 14));
+    assert_eq!(april.week_of(date(2026, 3, 31), 0), (4, 53));
+}
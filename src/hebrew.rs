@@ -0,0 +1,219 @@
+// The arithmetic Hebrew calendar, after Dershowitz and Reingold's Calendrical
+// Calculations. Months are numbered from Nisan, so the year starts with Tishrei,
+// month 7, and leap years insert Adar II as month 13.
+
+use crate::jdn::Ymd;
+
+/// JDN of 1 Tishrei AM 1, in 3761 BC.
+const EPOCH: i64 = 347998;
+
+/// Parts (1/1080 hour) in a day, and the length of a lunar month in days and parts.
+const PARTS_PER_DAY: i64 = 25920;
+const MONTH_DAYS: i64 = 29;
+const MONTH_PARTS: i64 = 13753;
+
+/// The molad of Tishrei AM 1 (BaHaRaD), in parts after the start of the first day.
+const MOLAD_EPOCH_PARTS: i64 = 12084;
+
+static MONTH_NAMES: [&str; 13] = [
+    "Nisan", "Iyyar", "Sivan", "Tammuz", "Av", "Elul", "Tishrei", "Cheshvan", "Kislev", "Tevet",
+    "Shevat", "Adar", "Adar II",
+];
+
+static HEBREW_MONTH_NAMES: [&str; 13] = [
+    "ניסן",
+    "אייר",
+    "סיון",
+    "תמוז",
+    "אב",
+    "אלול",
+    "תשרי",
+    "חשוון",
+    "כסלו",
+    "טבת",
+    "שבט",
+    "אדר",
+    "אדר ב׳",
+];
+
+/// Whether `year` has 13 months, 7 of every 19 years of the Metonic cycle.
+pub fn is_leap_year(year: i64) -> bool {
+    (7 * year + 1).rem_euclid(19) < 7
+}
+
+pub fn months_in_year(year: i64) -> u32 {
+    if is_leap_year(year) {
+        13
+    } else {
+        12
+    }
+}
+
+/// Days from the epoch to the molad of Tishrei of `year`, postponed by one day
+/// when it would put Rosh Hashanah on a Sunday, Wednesday or Friday.
+fn elapsed_days(year: i64) -> i64 {
+    let months = (235 * year - 234).div_euclid(19);
+    let parts = MOLAD_EPOCH_PARTS + MONTH_PARTS * months;
+    let days = MONTH_DAYS * months + parts.div_euclid(PARTS_PER_DAY);
+    if (3 * (days + 1)).rem_euclid(7) < 3 {
+        days + 1
+    } else {
+        days
+    }
+}
+
+/// The remaining postponements (dechiyot), which keep years between 353 and 385 days.
+fn year_length_correction(year: i64) -> i64 {
+    let (previous, current, next) = (
+        elapsed_days(year - 1),
+        elapsed_days(year),
+        elapsed_days(year + 1),
+    );
+    if next - current == 356 {
+        2
+    } else if current - previous == 382 {
+        1
+    } else {
+        0
+    }
+}
+
+/// The JDN of Rosh Hashanah, 1 Tishrei, of `year`.
+pub fn new_year(year: i64) -> i64 {
+    EPOCH + elapsed_days(year) + year_length_correction(year)
+}
+
+/// 353 to 355 days, or 383 to 385 in leap years.
+pub fn days_in_year(year: i64) -> u32 {
+    (new_year(year + 1) - new_year(year)) as u32
+}
+
+pub fn days_in_month(year: i64, month: u32) -> u32 {
+    let year_days = days_in_year(year);
+    match month {
+        2 | 4 | 6 | 10 | 13 => 29,
+        12 if !is_leap_year(year) => 29,
+        // Cheshvan is long and Kislev short in complete and deficient years
+        8 if year_days % 10 != 5 => 29,
+        9 if year_days % 10 == 3 => 29,
+        _ => 30,
+    }
+}
+
+/// The months of `year` in order, from Tishrei to Elul.
+pub fn months(year: i64) -> Vec<u32> {
+    (7..=months_in_year(year)).chain(1..7).collect()
+}
+
+/// The name of a month, in Hebrew script for Hebrew locales.
+pub fn month_name(year: i64, month: u32, locale: &str) -> String {
+    let hebrew = locale.starts_with("he");
+    let names = if hebrew {
+        &HEBREW_MONTH_NAMES
+    } else {
+        &MONTH_NAMES
+    };
+    match month {
+        12 if is_leap_year(year) && hebrew => "אדר א׳".to_string(),
+        12 if is_leap_year(year) => "Adar I".to_string(),
+        _ => names[(month as usize - 1) % 13].to_string(),
+    }
+}
+
+pub fn to_jdn(date: Ymd) -> Result<i64, String> {
+    if date.month < 1 || date.month > months_in_year(date.year) {
+        return Err(format!("invalid month in `{}`", date));
+    }
+    if date.day < 1 || date.day > days_in_month(date.year, date.month) {
+        return Err(format!("invalid day in `{}`", date));
+    }
+    let mut jdn = new_year(date.year) + date.day as i64 - 1;
+    for month in months(date.year)
+        .into_iter()
+        .take_while(|&month| month != date.month)
+    {
+        jdn += days_in_month(date.year, month) as i64;
+    }
+    Ok(jdn)
+}
+
+/// Converts a JDN within [`DAY_LIMIT`](crate::jdn::DAY_LIMIT), which keeps the estimate of
+/// its year from overflowing.
+pub fn from_jdn(jdn: i64) -> Ymd {
+    // the mean year is 35975351/98496 days
+    let mut year = ((jdn - EPOCH) * 98496).div_euclid(35975351) + 1;
+    while new_year(year) > jdn {
+        year -= 1;
+    }
+    while new_year(year + 1) <= jdn {
+        year += 1;
+    }
+    let mut first = new_year(year);
+    for month in months(year) {
+        let days = days_in_month(year, month) as i64;
+        if jdn < first + days {
+            return Ymd::new(year, month, (jdn - first) as u32 + 1);
+        }
+        first += days;
+    }
+    unreachable!("a year holds every day up to the next new year")
+}
+
+#[test]
+fn test_hebrew_year_lengths() {
+    assert!(is_leap_year(5784));
+    assert!(!is_leap_year(5785));
+    assert!(!is_leap_year(5786));
+    assert!(is_leap_year(5787));
+    assert_eq!(days_in_year(5784), 383);
+    assert_eq!(days_in_year(5785), 355);
+    assert_eq!(days_in_year(5786), 354);
+    assert_eq!(months(5784).len(), 13);
+    assert_eq!(months(5785), [7, 8, 9, 10, 11, 12, 1, 2, 3, 4, 5, 6]);
+    for year in 5600..5900 {
+        assert!(
+            matches!(days_in_year(year), 353..=355 | 383..=385),
+            "{}",
+            year
+        );
+        let total: u32 = months(year).iter().map(|&m| days_in_month(year, m)).sum();
+        assert_eq!(total, days_in_year(year), "{}", year);
+    }
+}
+
+#[test]
+fn test_hebrew_conversions() {
+    let test_cases = [
+        // Rosh Hashanah 5786, 2025-09-23
+        (Ymd::new(5786, 7, 1), 2460942),
+        // Rosh Hashanah 5785, 2024-10-03
+        (Ymd::new(5785, 7, 1), 2460587),
+        // Passover 5785, 2025-04-13
+        (Ymd::new(5785, 1, 15), 2460779),
+        // Purim 5784 in Adar II, 2024-03-24
+        (Ymd::new(5784, 13, 14), 2460394),
+        // 1 Tishrei AM 1
+        (Ymd::new(1, 7, 1), EPOCH),
+    ];
+    for (date, jdn) in test_cases.iter() {
+        assert_eq!(to_jdn(*date), Ok(*jdn), "{}", date);
+        assert_eq!(from_jdn(*jdn), *date, "{}", jdn);
+    }
+    assert!(to_jdn(Ymd::new(5785, 13, 1)).is_err());
+    assert!(to_jdn(Ymd::new(5785, 2, 30)).is_err());
+}
+
+#[test]
+fn test_hebrew_round_trip() {
+    for jdn in (2300000..2500000).step_by(37) {
+        assert_eq!(to_jdn(from_jdn(jdn)), Ok(jdn));
+    }
+}
+
+#[test]
+fn test_hebrew_month_names() {
+    assert_eq!(month_name(5785, 12, "en_US"), "Adar");
+    assert_eq!(month_name(5784, 12, "en_US"), "Adar I");
+    assert_eq!(month_name(5784, 13, "en_US"), "Adar II");
+    assert_eq!(month_name(5786, 7, "he_IL"), "תשרי");
+}
@@ -0,0 +1,149 @@
+use crate::parse_date;
+use ansi_term::{Color, Style};
+use chrono::NaiveDate;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// Dates and date ranges to mark on the calendar, along with the style used to mark them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Highlights {
+    ranges: Vec<RangeInclusive<NaiveDate>>,
+    style: Style,
+}
+
+impl Default for Highlights {
+    fn default() -> Self {
+        Highlights {
+            ranges: Vec::new(),
+            style: Color::Black.on(Color::Green),
+        }
+    }
+}
+
+impl Highlights {
+    /// Adds every date and range of a comma separated list such as
+    /// `2025-03-14,2025-07-01..2025-07-15`.
+    pub fn add(&mut self, list: &str) -> Result<(), String> {
+        for item in list.split(',').map(str::trim).filter(|i| !i.is_empty()) {
+            self.ranges.push(parse_range(item)?);
+        }
+        Ok(())
+    }
+
+    /// Adds the dates of a file with one list per line. Blank lines and `#` comments are skipped.
+    pub fn add_lines(&mut self, content: &str) -> Result<(), String> {
+        for line in content.lines() {
+            self.add(line.split('#').next().unwrap_or_default())?;
+        }
+        Ok(())
+    }
+
+    /// Sets the style from `+` separated words, e.g. `yellow+bold` or `black+on-cyan`.
+    pub fn set_style(&mut self, spec: &str) -> Result<(), String> {
+        self.style = parse_style(spec)?;
+        Ok(())
+    }
+
+    pub fn style(&self) -> Style {
+        self.style
+    }
+
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.ranges.iter().any(|range| range.contains(&date))
+    }
+}
+
+impl FromStr for Highlights {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut highlights = Highlights::default();
+        highlights.add(s)?;
+        Ok(highlights)
+    }
+}
+
+fn parse_range(s: &str) -> Result<RangeInclusive<NaiveDate>, String> {
+    match s.split_once("..") {
+        Some((start, end)) => {
+            let (start, end) = (parse_date(start)?, parse_date(end)?);
+            if start > end {
+                return Err(format!("invalid range `{}`, start is after end", s));
+            }
+            Ok(start..=end)
+        }
+        None => {
+            let date = parse_date(s)?;
+            Ok(date..=date)
+        }
+    }
+}
+
+fn parse_color(s: &str) -> Option<Color> {
+    match s {
+        "black" => Some(Color::Black),
+        "red" => Some(Color::Red),
+        "green" => Some(Color::Green),
+        "yellow" => Some(Color::Yellow),
+        "blue" => Some(Color::Blue),
+        "purple" | "magenta" => Some(Color::Purple),
+        "cyan" => Some(Color::Cyan),
+        "white" => Some(Color::White),
+        _ => None,
+    }
+}
+
+fn parse_style(spec: &str) -> Result<Style, String> {
+    spec.split('+')
+        .map(|word| word.trim().to_lowercase())
+        .try_fold(Style::new(), |style, word| match word.as_str() {
+            "bold" => Ok(style.bold()),
+            "underline" => Ok(style.underline()),
+            "italic" => Ok(style.italic()),
+            "reverse" => Ok(style.reverse()),
+            _ => match word.strip_prefix("on-") {
+                Some(background) => parse_color(background).map(|c| style.on(c)),
+                None => parse_color(&word).map(|c| style.fg(c)),
+            }
+            .ok_or_else(|| format!("invalid style `{}`", word)),
+        })
+}
+
+#[test]
+fn test_highlight_dates_and_ranges() {
+    let highlights: Highlights = "2025-03-14, 2025-07-01..2025-07-15".parse().unwrap();
+    let date = |m, d| NaiveDate::from_ymd_opt(2025, m, d).unwrap();
+    assert!(highlights.contains(date(3, 14)));
+    assert!(!highlights.contains(date(3, 15)));
+    assert!(highlights.contains(date(7, 1)));
+    assert!(highlights.contains(date(7, 8)));
+    assert!(highlights.contains(date(7, 15)));
+    assert!(!highlights.contains(date(7, 16)));
+}
+
+#[test]
+fn test_highlight_invalid_input() {
+    assert!("2025-02-30".parse::<Highlights>().is_err());
+    assert!("2025-07-15..2025-07-01".parse::<Highlights>().is_err());
+    assert!("tomorrow".parse::<Highlights>().is_err());
+}
+
+#[test]
+fn test_highlight_lines() {
+    let mut highlights = Highlights::default();
+    highlights
+        .add_lines("# vacation\n2025-08-04..2025-08-08\n\n2025-12-24 # eve\n")
+        .unwrap();
+    assert!(highlights.contains(NaiveDate::from_ymd_opt(2025, 8, 6).unwrap()));
+    assert!(highlights.contains(NaiveDate::from_ymd_opt(2025, 12, 24).unwrap()));
+}
+
+#[test]
+fn test_parse_style() {
+    assert_eq!(parse_style("yellow+bold"), Ok(Color::Yellow.bold()));
+    assert_eq!(
+        parse_style("black+on-cyan"),
+        Ok(Color::Black.on(Color::Cyan))
+    );
+    assert!(parse_style("sparkly").is_err());
+}
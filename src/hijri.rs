@@ -0,0 +1,209 @@
+// The tabular Islamic calendar: 30-year cycles of 19 common years of 354 days and
+// 11 leap years of 355 days, with months alternating between 30 and 29 days and
+// the leap day closing Dhu al-Hijjah.
+
+use crate::jdn::Ymd;
+use std::str::FromStr;
+
+/// JDN of 1 Muharram AH 1, Friday 16 July 622 Julian, the civil epoch.
+const EPOCH: i64 = 1948440;
+
+const CYCLE_YEARS: i64 = 30;
+const CYCLE_DAYS: i64 = 10631;
+
+static MONTH_NAMES: [&str; 12] = [
+    "Muharram",
+    "Safar",
+    "Rabi al-Awwal",
+    "Rabi al-Thani",
+    "Jumada al-Ula",
+    "Jumada al-Akhira",
+    "Rajab",
+    "Shaban",
+    "Ramadan",
+    "Shawwal",
+    "Dhu al-Qadah",
+    "Dhu al-Hijjah",
+];
+
+static ARABIC_MONTH_NAMES: [&str; 12] = [
+    "محرم",
+    "صفر",
+    "ربيع الأول",
+    "ربيع الآخر",
+    "جمادى الأولى",
+    "جمادى الآخرة",
+    "رجب",
+    "شعبان",
+    "رمضان",
+    "شوال",
+    "ذو القعدة",
+    "ذو الحجة",
+];
+
+/// Which years of each 30-year cycle are leap years.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HijriLeaps {
+    /// 2, 5, 7, 10, 13, 15, 18, 21, 24, 26, 29, after Kushyar ibn Labban
+    Year15,
+    /// 2, 5, 7, 10, 13, 16, 18, 21, 24, 26, 29, the most common variant
+    #[default]
+    Year16,
+    /// 2, 5, 8, 10, 13, 16, 19, 21, 24, 27, 29, used by the Fatimids and the Bohras
+    Fatimid,
+    /// 2, 5, 8, 11, 13, 16, 19, 21, 24, 27, 30, after Habash al-Hasib
+    Habash,
+}
+
+impl HijriLeaps {
+    fn years(self) -> [i64; 11] {
+        match self {
+            HijriLeaps::Year15 => [2, 5, 7, 10, 13, 15, 18, 21, 24, 26, 29],
+            HijriLeaps::Year16 => [2, 5, 7, 10, 13, 16, 18, 21, 24, 26, 29],
+            HijriLeaps::Fatimid => [2, 5, 8, 10, 13, 16, 19, 21, 24, 27, 29],
+            HijriLeaps::Habash => [2, 5, 8, 11, 13, 16, 19, 21, 24, 27, 30],
+        }
+    }
+}
+
+impl FromStr for HijriLeaps {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "15" => Ok(HijriLeaps::Year15),
+            "16" => Ok(HijriLeaps::Year16),
+            "fatimid" => Ok(HijriLeaps::Fatimid),
+            "habash" => Ok(HijriLeaps::Habash),
+            _ => Err(format!(
+                "invalid leap year pattern `{}`, expected 15, 16, fatimid or habash",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for HijriLeaps {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            HijriLeaps::Year15 => "15",
+            HijriLeaps::Year16 => "16",
+            HijriLeaps::Fatimid => "fatimid",
+            HijriLeaps::Habash => "habash",
+        })
+    }
+}
+
+pub fn is_leap_year(year: i64, leaps: HijriLeaps) -> bool {
+    let in_cycle = (year - 1).rem_euclid(CYCLE_YEARS) + 1;
+    leaps.years().contains(&in_cycle)
+}
+
+pub fn days_in_month(year: i64, month: u32, leaps: HijriLeaps) -> u32 {
+    match month {
+        12 if is_leap_year(year, leaps) => 30,
+        _ if month % 2 == 1 => 30,
+        _ => 29,
+    }
+}
+
+/// Days from the epoch to the first day of `year`.
+fn days_before_year(year: i64, leaps: HijriLeaps) -> i64 {
+    let cycles = (year - 1).div_euclid(CYCLE_YEARS);
+    let in_cycle = (year - 1).rem_euclid(CYCLE_YEARS);
+    let leap_years = leaps.years().iter().filter(|&&y| y <= in_cycle).count() as i64;
+    cycles * CYCLE_DAYS + in_cycle * 354 + leap_years
+}
+
+/// The name of a month, in Arabic script for Arabic locales.
+pub fn month_name(month: u32, locale: &str) -> String {
+    let names = if locale.starts_with("ar") {
+        &ARABIC_MONTH_NAMES
+    } else {
+        &MONTH_NAMES
+    };
+    names[(month as usize - 1) % 12].to_string()
+}
+
+pub fn to_jdn(date: Ymd, leaps: HijriLeaps) -> Result<i64, String> {
+    if date.month < 1 || date.month > 12 {
+        return Err(format!("invalid month in `{}`", date));
+    }
+    if date.day < 1 || date.day > days_in_month(date.year, date.month, leaps) {
+        return Err(format!("invalid day in `{}`", date));
+    }
+    let month = date.month as i64;
+    let days_before_month = 29 * (month - 1) + month / 2;
+    Ok(EPOCH + days_before_year(date.year, leaps) + days_before_month + date.day as i64 - 1)
+}
+
+pub fn from_jdn(jdn: i64, leaps: HijriLeaps) -> Ymd {
+    let days = jdn - EPOCH;
+    let mut year = (CYCLE_YEARS * days).div_euclid(CYCLE_DAYS) + 1;
+    while days_before_year(year, leaps) > days {
+        year -= 1;
+    }
+    while days_before_year(year + 1, leaps) <= days {
+        year += 1;
+    }
+    let mut day = days - days_before_year(year, leaps);
+    let mut month = 1;
+    while day >= days_in_month(year, month, leaps) as i64 {
+        day -= days_in_month(year, month, leaps) as i64;
+        month += 1;
+    }
+    Ymd::new(year, month, day as u32 + 1)
+}
+
+#[test]
+fn test_hijri_leap_years() {
+    let leap_years: Vec<i64> = (1..=30)
+        .filter(|&y| is_leap_year(y, HijriLeaps::Year16))
+        .collect();
+    assert_eq!(leap_years, [2, 5, 7, 10, 13, 16, 18, 21, 24, 26, 29]);
+    assert!(is_leap_year(1445, HijriLeaps::Year16));
+    assert!(!is_leap_year(1446, HijriLeaps::Year16));
+    assert!(is_leap_year(15, HijriLeaps::Year15));
+    assert!(!is_leap_year(15, HijriLeaps::Year16));
+    assert_eq!(days_before_year(31, HijriLeaps::Habash), CYCLE_DAYS);
+}
+
+#[test]
+fn test_hijri_conversions() {
+    let test_cases = [
+        (Ymd::new(1, 1, 1), EPOCH),
+        // 1 Ramadan 1446, 2025-03-01
+        (Ymd::new(1446, 9, 1), 2460736),
+        // 1 Shawwal 1446, 2025-03-31, while Umm al-Qura had 2025-03-30
+        (Ymd::new(1446, 10, 1), 2460766),
+        // 1 Muharram 1447, 2025-06-27, while Umm al-Qura had 2025-06-26
+        (Ymd::new(1447, 1, 1), 2460854),
+    ];
+    for (date, jdn) in test_cases.iter() {
+        assert_eq!(to_jdn(*date, HijriLeaps::Year16), Ok(*jdn), "{}", date);
+        assert_eq!(from_jdn(*jdn, HijriLeaps::Year16), *date, "{}", jdn);
+    }
+    assert!(to_jdn(Ymd::new(1446, 12, 30), HijriLeaps::Year16).is_err());
+    assert!(to_jdn(Ymd::new(1445, 12, 30), HijriLeaps::Year16).is_ok());
+    assert!(to_jdn(Ymd::new(1446, 2, 30), HijriLeaps::Year16).is_err());
+}
+
+#[test]
+fn test_hijri_round_trip() {
+    for leaps in [
+        HijriLeaps::Year15,
+        HijriLeaps::Year16,
+        HijriLeaps::Fatimid,
+        HijriLeaps::Habash,
+    ] {
+        for jdn in (1900000..2600000).step_by(97) {
+            assert_eq!(to_jdn(from_jdn(jdn, leaps), leaps), Ok(jdn));
+        }
+    }
+}
+
+#[test]
+fn test_hijri_month_names() {
+    assert_eq!(month_name(9, "en_US"), "Ramadan");
+    assert_eq!(month_name(9, "ar_SA"), "رمضان");
+}
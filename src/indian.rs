@@ -0,0 +1,274 @@
+// The Indian National Calendar, counting Saka years from 78 AD. The year starts on
+// 22 March, or 21 March in Gregorian leap years, with Chaitra, which then has 31 days,
+// followed by five months of 31 days and six of 30.
+
+use crate::jdn::{self, DateSystem, Ymd};
+
+/// Saka years are Gregorian years less 78 from Chaitra to December.
+const SAKA_OFFSET: i64 = 78;
+
+static MONTH_NAMES: [&str; 12] = [
+    "Chaitra",
+    "Vaishakha",
+    "Jyeshtha",
+    "Ashadha",
+    "Shravana",
+    "Bhadra",
+    "Ashvin",
+    "Kartika",
+    "Agrahayana",
+    "Pausha",
+    "Magha",
+    "Phalguna",
+];
+
+static DEVANAGARI_MONTH_NAMES: [&str; 12] = [
+    "चैत्र",
+    "वैशाख",
+    "ज्येष्ठ",
+    "आषाढ़",
+    "श्रावण",
+    "भाद्र",
+    "आश्विन",
+    "कार्तिक",
+    "अग्रहायण",
+    "पौष",
+    "माघ",
+    "फाल्गुन",
+];
+
+static BENGALI_MONTH_NAMES: [&str; 12] = [
+    "চৈত্র",
+    "বৈশাখ",
+    "জ্যৈষ্ঠ",
+    "আষাঢ়",
+    "শ্রাবণ",
+    "ভাদ্র",
+    "আশ্বিন",
+    "কার্তিক",
+    "অগ্রহায়ণ",
+    "পৌষ",
+    "মাঘ",
+    "ফাল্গুন",
+];
+
+static GUJARATI_MONTH_NAMES: [&str; 12] = [
+    "ચૈત્ર",
+    "વૈશાખ",
+    "જ્યેષ્ઠ",
+    "અષાઢ",
+    "શ્રાવણ",
+    "ભાદ્ર",
+    "આશ્વિન",
+    "કાર્તિક",
+    "અગ્રહાયણ",
+    "પૌષ",
+    "માઘ",
+    "ફાલ્ગુન",
+];
+
+static GURMUKHI_MONTH_NAMES: [&str; 12] = [
+    "ਚੇਤ",
+    "ਵੈਸਾਖ",
+    "ਜੇਠ",
+    "ਹਾੜ",
+    "ਸਾਵਣ",
+    "ਭਾਦੋਂ",
+    "ਅੱਸੂ",
+    "ਕੱਤਕ",
+    "ਮੱਘਰ",
+    "ਪੋਹ",
+    "ਮਾਘ",
+    "ਫੱਗਣ",
+];
+
+static ODIA_MONTH_NAMES: [&str; 12] = [
+    "ଚୈତ୍ର",
+    "ବୈଶାଖ",
+    "ଜ୍ୟେଷ୍ଠ",
+    "ଆଷାଢ଼",
+    "ଶ୍ରାବଣ",
+    "ଭାଦ୍ର",
+    "ଆଶ୍ୱିନ",
+    "କାର୍ତ୍ତିକ",
+    "ଅଗ୍ରହାୟଣ",
+    "ପୌଷ",
+    "ମାଘ",
+    "ଫାଲ୍ଗୁନ",
+];
+
+static TAMIL_MONTH_NAMES: [&str; 12] = [
+    "சைத்ரம்",
+    "வைசாகம்",
+    "ஜ்யேஷ்டம்",
+    "ஆஷாடம்",
+    "ஷ்ராவணம்",
+    "பாத்ரம்",
+    "ஆஸ்வினம்",
+    "கார்த்திகம்",
+    "அக்ரஹாயணம்",
+    "பௌஷம்",
+    "மாகம்",
+    "பால்குனம்",
+];
+
+static TELUGU_MONTH_NAMES: [&str; 12] = [
+    "చైత్ర",
+    "వైశాఖ",
+    "జ్యేష్ఠ",
+    "ఆషాఢ",
+    "శ్రావణ",
+    "భాద్ర",
+    "ఆశ్విన",
+    "కార్తీక",
+    "అగ్రహాయణ",
+    "పౌష",
+    "మాఘ",
+    "ఫాల్గుణ",
+];
+
+static KANNADA_MONTH_NAMES: [&str; 12] = [
+    "ಚೈತ್ರ",
+    "ವೈಶಾಖ",
+    "ಜ್ಯೇಷ್ಠ",
+    "ಆಷಾಢ",
+    "ಶ್ರಾವಣ",
+    "ಭಾದ್ರ",
+    "ಆಶ್ವಿನ",
+    "ಕಾರ್ತಿಕ",
+    "ಅಗ್ರಹಾಯಣ",
+    "ಪೌಷ",
+    "ಮಾಘ",
+    "ಫಾಲ್ಗುಣ",
+];
+
+static MALAYALAM_MONTH_NAMES: [&str; 12] = [
+    "ചൈത്രം",
+    "വൈശാഖം",
+    "ജ്യേഷ്ഠം",
+    "ആഷാഢം",
+    "ശ്രാവണം",
+    "ഭാദ്രം",
+    "ആശ്വിനം",
+    "കാർത്തികം",
+    "അഗ്രഹായണം",
+    "പൗഷം",
+    "മാഘം",
+    "ഫാൽഗുനം",
+];
+
+/// Whether `year` has a 31-day Chaitra, as the Gregorian year it starts in is a leap year.
+pub fn is_leap_year(year: i64) -> bool {
+    let gregorian = year + SAKA_OFFSET;
+    gregorian % 4 == 0 && (gregorian % 100 != 0 || gregorian % 400 == 0)
+}
+
+pub fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        1 if is_leap_year(year) => 31,
+        1 => 30,
+        2..=6 => 31,
+        7..=12 => 30,
+        _ => 0,
+    }
+}
+
+/// The name of a month in the script of the locale's language: Devanagari for Hindi,
+/// Marathi and Sanskrit, and Bengali, Gujarati, Gurmukhi, Odia, Tamil, Telugu, Kannada or
+/// Malayalam script for those languages, with the Punjabi names in Gurmukhi.
+pub fn month_name(month: u32, locale: &str) -> String {
+    let language = locale.split(['_', '.', '@']).next();
+    let names = match language.unwrap_or_default() {
+        "hi" | "mr" | "sa" => &DEVANAGARI_MONTH_NAMES,
+        "bn" => &BENGALI_MONTH_NAMES,
+        "gu" => &GUJARATI_MONTH_NAMES,
+        "pa" => &GURMUKHI_MONTH_NAMES,
+        "or" => &ODIA_MONTH_NAMES,
+        "ta" => &TAMIL_MONTH_NAMES,
+        "te" => &TELUGU_MONTH_NAMES,
+        "kn" => &KANNADA_MONTH_NAMES,
+        "ml" => &MALAYALAM_MONTH_NAMES,
+        _ => &MONTH_NAMES,
+    };
+    names[(month as usize - 1) % 12].to_string()
+}
+
+/// The JDN of 1 Chaitra of `year`.
+fn new_year(year: i64) -> i64 {
+    let day = if is_leap_year(year) { 21 } else { 22 };
+    jdn::to_jdn(Ymd::new(year + SAKA_OFFSET, 3, day), DateSystem::Gregorian)
+        .expect("March 21 and 22 are valid Gregorian dates")
+}
+
+pub fn to_jdn(date: Ymd) -> Result<i64, String> {
+    if date.month < 1 || date.month > 12 {
+        return Err(format!("invalid month in `{}`", date));
+    }
+    if date.day < 1 || date.day > days_in_month(date.year, date.month) {
+        return Err(format!("invalid day in `{}`", date));
+    }
+    let days_before_month: i64 = (1..date.month)
+        .map(|month| days_in_month(date.year, month) as i64)
+        .sum();
+    Ok(new_year(date.year) + days_before_month + date.day as i64 - 1)
+}
+
+pub fn from_jdn(jdn: i64) -> Ymd {
+    let gregorian = jdn::from_jdn(jdn, DateSystem::Gregorian);
+    let mut year = gregorian.year - SAKA_OFFSET;
+    if jdn < new_year(year) {
+        year -= 1;
+    }
+    let mut day = jdn - new_year(year);
+    let mut month = 1;
+    while day >= days_in_month(year, month) as i64 {
+        day -= days_in_month(year, month) as i64;
+        month += 1;
+    }
+    Ymd::new(year, month, day as u32 + 1)
+}
+
+#[test]
+fn test_indian_conversions() {
+    let test_cases = [
+        // 1 Chaitra 1947, 2025-03-22
+        (Ymd::new(1947, 1, 1), 2460757),
+        // 1 Chaitra 1946, 2024-03-21 in a leap year
+        (Ymd::new(1946, 1, 1), 2460391),
+        // 31 Chaitra 1946, 2024-04-20
+        (Ymd::new(1946, 1, 31), 2460421),
+        // 6 Magha 1946, Republic Day, 2025-01-26
+        (Ymd::new(1946, 11, 6), 2460702),
+        // 30 Phalguna 1946, 2025-03-21
+        (Ymd::new(1946, 12, 30), 2460756),
+    ];
+    for (date, jdn) in test_cases.iter() {
+        assert_eq!(to_jdn(*date), Ok(*jdn), "{}", date);
+        assert_eq!(from_jdn(*jdn), *date, "{}", jdn);
+    }
+    assert!(to_jdn(Ymd::new(1947, 1, 31)).is_err());
+    assert!(to_jdn(Ymd::new(1947, 13, 1)).is_err());
+}
+
+#[test]
+fn test_indian_round_trip() {
+    for jdn in (2000000..2600000).step_by(89) {
+        assert_eq!(to_jdn(from_jdn(jdn)), Ok(jdn));
+    }
+}
+
+#[test]
+fn test_indian_month_names() {
+    assert_eq!(month_name(1, "en_IN"), "Chaitra");
+    assert_eq!(month_name(12, "hi_IN"), "फाल्गुन");
+    assert_eq!(month_name(2, "bn_IN"), "বৈশাখ");
+    assert_eq!(month_name(1, "ta_IN"), "சைத்ரம்");
+    assert_eq!(month_name(1, "te_IN"), "చైత్ర");
+    assert_eq!(month_name(12, "kn_IN"), "ಫಾಲ್ಗುಣ");
+    assert_eq!(month_name(1, "ml_IN.UTF-8"), "ചൈത്രം");
+    assert_eq!(month_name(2, "gu_IN"), "વૈશાખ");
+    assert_eq!(month_name(1, "pa_IN"), "ਚੇਤ");
+    assert_eq!(month_name(1, "or_IN"), "ଚୈତ୍ର");
+    // Santali is not Sanskrit
+    assert_eq!(month_name(1, "sat_IN"), "Chaitra");
+}
@@ -0,0 +1,142 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+/// A week of an ISO 8601 week-numbering year, e.g. `2025-W33`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IsoWeek {
+    year: i32,
+    week: u32,
+}
+
+impl IsoWeek {
+    /// The week `week` of the week-numbering year `year`, if the year has that week.
+    pub fn new(year: i32, week: u32) -> Option<IsoWeek> {
+        from_iso_week_date(year, week, 1).map(|_| IsoWeek { year, week })
+    }
+
+    pub fn of(date: NaiveDate) -> IsoWeek {
+        let iso = date.iso_week();
+        IsoWeek {
+            year: iso.year(),
+            week: iso.week(),
+        }
+    }
+
+    /// The week-numbering year, which differs from the calendar year around new year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// The week of the year, 1 to 52 or 53.
+    pub fn week(&self) -> u32 {
+        self.week
+    }
+
+    /// The Monday starting the week.
+    pub fn first_day(&self) -> NaiveDate {
+        from_iso_week_date(self.year, self.week, 1).expect("weeks are validated on creation")
+    }
+
+    /// The seven days of the week, Monday first.
+    pub fn days(&self) -> Vec<NaiveDate> {
+        self.first_day().iter_days().take(7).collect()
+    }
+}
+
+impl FromStr for IsoWeek {
+    type Err = String;
+
+    /// Parses `YYYY-Www` or `YYYYWww`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid week `{}`, expected YYYY-Www", s);
+        let (year, week) = s
+            .split_once("-W")
+            .or_else(|| s.split_once('W'))
+            .ok_or_else(invalid)?;
+        let year = year.parse::<i32>().map_err(|_| invalid())?;
+        let week = week.parse::<u32>().map_err(|_| invalid())?;
+        IsoWeek::new(year, week).ok_or_else(|| format!("{} has no week {}", year, week))
+    }
+}
+
+impl std::fmt::Display for IsoWeek {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}-W{:02}", self.year, self.week)
+    }
+}
+
+/// Converts a date to its ISO week date: week-numbering year, week and weekday (1 is Monday).
+pub fn to_iso_week_date(date: NaiveDate) -> (i32, u32, u32) {
+    let week = IsoWeek::of(date);
+    (week.year, week.week, date.weekday().number_from_monday())
+}
+
+/// Converts an ISO week date back to a calendar date, if the week and weekday exist.
+pub fn from_iso_week_date(year: i32, week: u32, weekday: u32) -> Option<NaiveDate> {
+    let weekday = Weekday::try_from(weekday.checked_sub(1)? as u8).ok()?;
+    NaiveDate::from_isoywd_opt(year, week, weekday)
+}
+
+#[test]
+fn test_to_iso_week_date() {
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    assert_eq!(to_iso_week_date(date(2025, 8, 14)), (2025, 33, 4));
+    assert_eq!(to_iso_week_date(date(2024, 12, 30)), (2025, 1, 1));
+    assert_eq!(to_iso_week_date(date(2021, 1, 3)), (2020, 53, 7));
+}
+
+#[test]
+fn test_from_iso_week_date() {
+    assert_eq!(
+        from_iso_week_date(2025, 1, 1),
+        NaiveDate::from_ymd_opt(2024, 12, 30)
+    );
+    assert_eq!(
+        from_iso_week_date(2020, 53, 7),
+        NaiveDate::from_ymd_opt(2021, 1, 3)
+    );
+    assert_eq!(from_iso_week_date(2025, 53, 1), None);
+    assert_eq!(from_iso_week_date(2025, 1, 0), None);
+    assert_eq!(from_iso_week_date(2025, 1, 8), None);
+}
+
+#[test]
+fn test_new_iso_week() {
+    let week = IsoWeek::new(2020, 53).unwrap();
+    assert_eq!((week.year(), week.week()), (2020, 53));
+    assert_eq!(
+        week.first_day(),
+        NaiveDate::from_ymd_opt(2020, 12, 28).unwrap()
+    );
+    assert_eq!(IsoWeek::new(2025, 53), None);
+    assert_eq!(IsoWeek::new(2025, 0), None);
+}
+
+#[test]
+fn test_parse_iso_week() {
+    assert_eq!(
+        "2025-W33".parse(),
+        Ok(IsoWeek {
+            year: 2025,
+            week: 33
+        })
+    );
+    assert_eq!(
+        "2020W53".parse(),
+        Ok(IsoWeek {
+            year: 2020,
+            week: 53
+        })
+    );
+    assert!("2025-W53".parse::<IsoWeek>().is_err());
+    assert!("2025-33".parse::<IsoWeek>().is_err());
+    assert_eq!(
+        IsoWeek {
+            year: 2025,
+            week: 3
+        }
+        .to_string(),
+        "2025-W03"
+    );
+}
@@ -0,0 +1,588 @@
+use crate::coptic::{self, COPTIC_EPOCH, ETHIOPIAN_EPOCH};
+use crate::era;
+use crate::hebrew;
+use crate::hijri::{self, HijriLeaps};
+use crate::indian;
+use crate::maya::MayaDate;
+use crate::persian;
+use crate::republican::{self, DECADE_DAYS};
+use chrono::NaiveDate;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+/// JDN of 1582-10-15, the first day of the Gregorian calendar.
+const GREGORIAN_REFORM_JDN: i64 = 2299161;
+
+/// JDN of 1858-11-17, MJD 0.
+const MJD_EPOCH: i64 = 2400001;
+
+/// JDN of 0000-12-31 Gregorian, Rata Die 0.
+const RATA_DIE_EPOCH: i64 = 1721425;
+
+/// The largest year accepted by the conversions, either side of year 0.
+pub const YEAR_LIMIT: i64 = 2_000_000;
+
+/// The largest day count, JDN, MJD or Rata Die, accepted by the conversions, about 1.4
+/// million years either side of its epoch. Both limits keep the calendar arithmetic far
+/// from overflowing.
+pub const DAY_LIMIT: i64 = 500_000_000;
+
+/// The calendar a year, month and day are expressed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateSystem {
+    /// the proleptic Gregorian calendar
+    #[default]
+    Gregorian,
+    /// the proleptic Julian calendar
+    Julian,
+    /// the Julian calendar until 1582-10-04, followed by the Gregorian calendar from 1582-10-15
+    Reform,
+    /// the Hebrew calendar, with months numbered from Nisan
+    Hebrew,
+    /// the tabular Islamic calendar with the given leap years
+    Hijri(HijriLeaps),
+    /// the arithmetic Solar Hijri calendar, starting at Nowruz
+    Persian,
+    /// the Ethiopian calendar, with a 13th month of 5 or 6 days
+    Ethiopian,
+    /// the Coptic calendar, laid out like the Ethiopian calendar but counted from 284
+    Coptic,
+    /// the Indian National Calendar, counting Saka years from Chaitra
+    Indian,
+    /// the French Republican calendar, with 10-day décades instead of weeks
+    Republican,
+}
+
+impl FromStr for DateSystem {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "gregorian" => Ok(DateSystem::Gregorian),
+            "julian" => Ok(DateSystem::Julian),
+            "reform" => Ok(DateSystem::Reform),
+            "hebrew" => Ok(DateSystem::Hebrew),
+            "hijri" => Ok(DateSystem::Hijri(HijriLeaps::default())),
+            "persian" => Ok(DateSystem::Persian),
+            "ethiopian" => Ok(DateSystem::Ethiopian),
+            "coptic" => Ok(DateSystem::Coptic),
+            "indian" | "saka" => Ok(DateSystem::Indian),
+            "republican" | "french" => Ok(DateSystem::Republican),
+            _ => match s.strip_prefix("hijri:") {
+                Some(leaps) => Ok(DateSystem::Hijri(leaps.parse()?)),
+                None => Err(format!(
+                    "invalid calendar `{}`, expected gregorian, julian, reform, hebrew, hijri, persian, ethiopian, coptic, indian or republican",
+                    s
+                )),
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for DateSystem {
+    /// the name parsed by `--calendar`, e.g. `hijri:habash`
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DateSystem::Gregorian => f.write_str("gregorian"),
+            DateSystem::Julian => f.write_str("julian"),
+            DateSystem::Reform => f.write_str("reform"),
+            DateSystem::Hebrew => f.write_str("hebrew"),
+            DateSystem::Hijri(leaps) if *leaps == HijriLeaps::default() => f.write_str("hijri"),
+            DateSystem::Hijri(leaps) => write!(f, "hijri:{}", leaps),
+            DateSystem::Persian => f.write_str("persian"),
+            DateSystem::Ethiopian => f.write_str("ethiopian"),
+            DateSystem::Coptic => f.write_str("coptic"),
+            DateSystem::Indian => f.write_str("indian"),
+            DateSystem::Republican => f.write_str("republican"),
+        }
+    }
+}
+
+impl DateSystem {
+    /// The months of `year` in the order they are printed.
+    pub fn months(self, year: i64) -> Vec<u32> {
+        match self {
+            DateSystem::Hebrew => hebrew::months(year),
+            DateSystem::Ethiopian | DateSystem::Coptic | DateSystem::Republican => {
+                (1..=13).collect()
+            }
+            _ => (1..=12).collect(),
+        }
+    }
+
+    /// The number of days of a month, 0 for months the year does not have.
+    pub fn days_in_month(self, year: i64, month: u32) -> u32 {
+        match self {
+            DateSystem::Hebrew if (1..=hebrew::months_in_year(year)).contains(&month) => {
+                hebrew::days_in_month(year, month)
+            }
+            DateSystem::Hebrew => 0,
+            DateSystem::Ethiopian | DateSystem::Coptic => coptic::days_in_month(year, month),
+            DateSystem::Republican => republican::days_in_month(year, month),
+            DateSystem::Hijri(leaps) if (1..=12).contains(&month) => {
+                hijri::days_in_month(year, month, leaps)
+            }
+            _ if !(1..=12).contains(&month) => 0,
+            DateSystem::Persian => persian::days_in_month(year, month),
+            DateSystem::Indian => indian::days_in_month(year, month),
+            DateSystem::Reform if year == 1582 && month == 10 => 21,
+            DateSystem::Reform if year < 1582 => days_in_month(year, month, DateSystem::Julian),
+            _ => days_in_month(year, month, self),
+        }
+    }
+
+    /// The name of a month of the calendar, or `None` for the Gregorian month names
+    /// of the locale.
+    pub fn month_name(self, year: i64, month: u32, locale: &str) -> Option<String> {
+        match self {
+            DateSystem::Hebrew => Some(hebrew::month_name(year, month, locale)),
+            DateSystem::Hijri(_) => Some(hijri::month_name(month, locale)),
+            DateSystem::Persian => Some(persian::month_name(month, locale)),
+            DateSystem::Ethiopian => Some(coptic::ethiopian_month_name(month, locale)),
+            DateSystem::Coptic => Some(coptic::coptic_month_name(month)),
+            DateSystem::Indian => Some(indian::month_name(month, locale)),
+            DateSystem::Republican => Some(republican::month_name(month)),
+            _ => None,
+        }
+    }
+
+    /// The number of days of a week, or of a décade of the French Republican calendar.
+    pub fn week_length(self) -> u32 {
+        match self {
+            DateSystem::Republican => DECADE_DAYS,
+            _ => 7,
+        }
+    }
+
+    /// The day of the week of a JDN from 0 for Sunday, or of the décade from 0 for primidi.
+    pub fn weekday(self, jdn: i64) -> u32 {
+        match self {
+            DateSystem::Republican => (from_jdn(jdn, self).day - 1) % DECADE_DAYS,
+            _ => (jdn + 1).rem_euclid(7) as u32,
+        }
+    }
+
+    /// The names of the days of a week of [`DateSystem::week_length`] days, or `None` for
+    /// the weekday names of the locale.
+    pub fn week_day_names(self) -> Option<Vec<String>> {
+        match self {
+            DateSystem::Republican => Some(republican::day_names()),
+            _ => None,
+        }
+    }
+
+    /// The year as printed above a year of the calendar, e.g. `5786 AM`.
+    pub fn year_name(self, year: i64) -> String {
+        match self {
+            DateSystem::Julian => format!("{} (Julian)", year),
+            DateSystem::Hebrew => format!("{} AM", year),
+            DateSystem::Hijri(_) => format!("{} AH", year),
+            DateSystem::Persian => format!("{} AP", year),
+            DateSystem::Ethiopian => format!("{} (Ethiopian)", year),
+            DateSystem::Coptic => format!("{} (Coptic)", year),
+            DateSystem::Indian => format!("{} Saka", year),
+            DateSystem::Republican => format!("An {}", year),
+            _ => year.to_string(),
+        }
+    }
+}
+
+/// A year, month and day in a [`DateSystem`]. Years are astronomical, so 1 BC is year 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ymd {
+    pub year: i64,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl Ymd {
+    pub fn new(year: i64, month: u32, day: u32) -> Ymd {
+        Ymd { year, month, day }
+    }
+}
+
+impl FromStr for Ymd {
+    type Err = String;
+
+    /// Parses `YYYY-MM-DD`, where the year may be negative.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid date `{}`, expected YYYY-MM-DD", s);
+        let (sign, unsigned) = match s.strip_prefix('-') {
+            Some(rest) => (-1, rest),
+            None => (1, s),
+        };
+        let parts: Vec<&str> = unsigned.split('-').collect();
+        if parts.len() != 3 {
+            return Err(invalid());
+        }
+        let year = parts[0].parse::<i64>().map_err(|_| invalid())?;
+        let month = parts[1].parse().map_err(|_| invalid())?;
+        let day = parts[2].parse().map_err(|_| invalid())?;
+        Ok(Ymd::new(sign * year, month, day))
+    }
+}
+
+impl std::fmt::Display for Ymd {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+fn is_leap_year(year: i64, system: DateSystem) -> bool {
+    match system {
+        DateSystem::Julian => year.rem_euclid(4) == 0,
+        _ => year.rem_euclid(4) == 0 && (year.rem_euclid(100) != 0 || year.rem_euclid(400) == 0),
+    }
+}
+
+fn days_in_month(year: i64, month: u32, system: DateSystem) -> u32 {
+    match month {
+        2 if is_leap_year(year, system) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Fliegel and Van Flandern style conversions, with March as the first month of the
+// computational year so that the leap day comes last.
+fn gregorian_to_jdn(date: Ymd) -> i64 {
+    let a = (14 - date.month as i64) / 12;
+    let y = date.year + 4800 - a;
+    let m = date.month as i64 + 12 * a - 3;
+    date.day as i64 + (153 * m + 2) / 5 + 365 * y + y.div_euclid(4) - y.div_euclid(100)
+        + y.div_euclid(400)
+        - 32045
+}
+
+fn julian_to_jdn(date: Ymd) -> i64 {
+    let a = (14 - date.month as i64) / 12;
+    let y = date.year + 4800 - a;
+    let m = date.month as i64 + 12 * a - 3;
+    date.day as i64 + (153 * m + 2) / 5 + 365 * y + y.div_euclid(4) - 32083
+}
+
+fn jdn_to_gregorian(jdn: i64) -> Ymd {
+    let a = jdn + 32044;
+    let b = (4 * a + 3).div_euclid(146097);
+    let c = a - (146097 * b).div_euclid(4);
+    from_march_based(b * 100, c)
+}
+
+fn jdn_to_julian(jdn: i64) -> Ymd {
+    from_march_based(0, jdn + 32082)
+}
+
+fn from_march_based(centuries: i64, c: i64) -> Ymd {
+    let d = (4 * c + 3).div_euclid(1461);
+    let e = c - (1461 * d).div_euclid(4);
+    let m = (5 * e + 2) / 153;
+    let day = (e - (153 * m + 2) / 5 + 1) as u32;
+    let month = (m + 3 - 12 * (m / 10)) as u32;
+    let year = centuries + d - 4800 + m / 10;
+    Ymd::new(year, month, day)
+}
+
+/// Converts a calendar date to its Julian Day Number, the day count starting at
+/// -4713-11-24 Gregorian (4714 BC).
+pub fn to_jdn(date: Ymd, system: DateSystem) -> Result<i64, String> {
+    if !(-YEAR_LIMIT..=YEAR_LIMIT).contains(&date.year) {
+        return Err(format!(
+            "year out of range in `{}`, expected at most {} years from year 0",
+            date, YEAR_LIMIT
+        ));
+    }
+    match system {
+        DateSystem::Hebrew => return hebrew::to_jdn(date),
+        DateSystem::Hijri(leaps) => return hijri::to_jdn(date, leaps),
+        DateSystem::Persian => return persian::to_jdn(date),
+        DateSystem::Ethiopian => return coptic::to_jdn(date, ETHIOPIAN_EPOCH),
+        DateSystem::Coptic => return coptic::to_jdn(date, COPTIC_EPOCH),
+        DateSystem::Indian => return indian::to_jdn(date),
+        DateSystem::Republican => return republican::to_jdn(date),
+        _ => {}
+    }
+    if date.month < 1 || date.month > 12 {
+        return Err(format!("invalid month in `{}`", date));
+    }
+    let (month_system, jdn) = match system {
+        DateSystem::Gregorian => (system, gregorian_to_jdn(date)),
+        DateSystem::Julian => (system, julian_to_jdn(date)),
+        DateSystem::Reform => {
+            if (date.year, date.month, date.day) >= (1582, 10, 15) {
+                (DateSystem::Gregorian, gregorian_to_jdn(date))
+            } else if (date.year, date.month, date.day) <= (1582, 10, 4) {
+                (DateSystem::Julian, julian_to_jdn(date))
+            } else {
+                return Err(format!("`{}` was skipped by the Gregorian reform", date));
+            }
+        }
+        DateSystem::Hebrew
+        | DateSystem::Hijri(_)
+        | DateSystem::Persian
+        | DateSystem::Ethiopian
+        | DateSystem::Coptic
+        | DateSystem::Indian
+        | DateSystem::Republican => {
+            unreachable!("converted above")
+        }
+    };
+    if date.day < 1 || date.day > days_in_month(date.year, date.month, month_system) {
+        return Err(format!("invalid day in `{}`", date));
+    }
+    Ok(jdn)
+}
+
+/// Converts a Julian Day Number to a calendar date.
+pub fn from_jdn(jdn: i64, system: DateSystem) -> Ymd {
+    match system {
+        DateSystem::Gregorian => jdn_to_gregorian(jdn),
+        DateSystem::Julian => jdn_to_julian(jdn),
+        DateSystem::Reform if jdn >= GREGORIAN_REFORM_JDN => jdn_to_gregorian(jdn),
+        DateSystem::Reform => jdn_to_julian(jdn),
+        DateSystem::Hebrew => hebrew::from_jdn(jdn),
+        DateSystem::Hijri(leaps) => hijri::from_jdn(jdn, leaps),
+        DateSystem::Persian => persian::from_jdn(jdn),
+        DateSystem::Ethiopian => coptic::from_jdn(jdn, ETHIOPIAN_EPOCH),
+        DateSystem::Coptic => coptic::from_jdn(jdn, COPTIC_EPOCH),
+        DateSystem::Indian => indian::from_jdn(jdn),
+        DateSystem::Republican => republican::from_jdn(jdn),
+    }
+}
+
+/// Checks that a JDN, MJD or Rata Die is within [`DAY_LIMIT`] days of its epoch.
+pub fn check_day_count(days: i64) -> Result<i64, String> {
+    if !(-DAY_LIMIT..=DAY_LIMIT).contains(&days) {
+        return Err(format!(
+            "day count {} out of range, expected at most {} days from the epoch",
+            days, DAY_LIMIT
+        ));
+    }
+    Ok(days)
+}
+
+/// The Modified Julian Day of the day starting at midnight of the given JDN.
+pub fn jdn_to_mjd(jdn: i64) -> i64 {
+    jdn - MJD_EPOCH
+}
+
+pub fn mjd_to_jdn(mjd: i64) -> i64 {
+    mjd + MJD_EPOCH
+}
+
+/// The Rata Die, the day count where 0001-01-01 Gregorian is day 1.
+pub fn jdn_to_rata_die(jdn: i64) -> i64 {
+    jdn - RATA_DIE_EPOCH
+}
+
+pub fn rata_die_to_jdn(rata_die: i64) -> i64 {
+    rata_die + RATA_DIE_EPOCH
+}
+
+/// Prints a day as Gregorian, Julian, Hebrew, Hijri, Persian, Ethiopian, Coptic, Indian,
+/// French Republican and Japanese era dates, as a Maya date under the given correlation
+/// and as JDN, MJD and Rata Die day counts.
+pub fn display_jdn(jdn: i64, correlation: i64) {
+    for line in jdn_printable(jdn, correlation) {
+        println!("{}", line);
+    }
+}
+
+fn jdn_printable(jdn: i64, correlation: i64) -> Vec<String> {
+    let japanese = i32::try_from(jdn_to_rata_die(jdn))
+        .ok()
+        .and_then(NaiveDate::from_num_days_from_ce_opt)
+        .and_then(era::date_name)
+        .unwrap_or_default();
+    let maya = MayaDate::from_jdn(jdn, correlation);
+    vec![
+        format!("Gregorian {}", from_jdn(jdn, DateSystem::Gregorian)),
+        format!("Julian    {}", from_jdn(jdn, DateSystem::Julian)),
+        named_printable("Hebrew   ", jdn, DateSystem::Hebrew),
+        named_printable("Hijri    ", jdn, DateSystem::Hijri(HijriLeaps::default())),
+        named_printable("Persian  ", jdn, DateSystem::Persian),
+        named_printable("Ethiopian", jdn, DateSystem::Ethiopian),
+        named_printable("Coptic   ", jdn, DateSystem::Coptic),
+        named_printable("Indian   ", jdn, DateSystem::Indian),
+        named_printable("French   ", jdn, DateSystem::Republican),
+        format!("Japanese  {}", japanese),
+        format!(
+            "Maya      {} ({} {})",
+            maya,
+            maya.tzolkin_name(),
+            maya.haab_name()
+        ),
+        format!("JDN       {}", jdn),
+        format!("MJD       {}", jdn_to_mjd(jdn)),
+        format!("Rata Die  {}", jdn_to_rata_die(jdn)),
+    ]
+}
+
+/// a date followed by its day, month name and year, e.g. `5785-12-14 (14 Adar 5785)`
+fn named_printable(label: &str, jdn: i64, system: DateSystem) -> String {
+    let date = from_jdn(jdn, system);
+    format!(
+        "{} {} ({} {} {})",
+        label,
+        date,
+        date.day,
+        system
+            .month_name(date.year, date.month, "")
+            .unwrap_or_default(),
+        date.year
+    )
+}
+
+#[test]
+fn test_to_jdn() {
+    let test_cases = [
+        (Ymd::new(2000, 1, 1), DateSystem::Gregorian, 2451545),
+        (Ymd::new(2025, 3, 14), DateSystem::Gregorian, 2460749),
+        (Ymd::new(1858, 11, 17), DateSystem::Gregorian, 2400001),
+        (Ymd::new(1, 1, 1), DateSystem::Gregorian, 1721426),
+        (Ymd::new(-4713, 11, 24), DateSystem::Gregorian, 0),
+        (Ymd::new(-4712, 1, 1), DateSystem::Julian, 0),
+        (Ymd::new(1582, 10, 4), DateSystem::Julian, 2299160),
+        (Ymd::new(1582, 10, 4), DateSystem::Reform, 2299160),
+        (Ymd::new(1582, 10, 15), DateSystem::Reform, 2299161),
+        (Ymd::new(1000, 2, 29), DateSystem::Julian, 2086367),
+    ];
+    for (date, system, jdn) in test_cases.iter() {
+        assert_eq!(to_jdn(*date, *system), Ok(*jdn), "{} {:?}", date, system);
+        assert_eq!(from_jdn(*jdn, *system), *date, "{} {:?}", jdn, system);
+    }
+}
+
+#[test]
+fn test_date_system_names() {
+    for name in [
+        "gregorian",
+        "julian",
+        "reform",
+        "hebrew",
+        "hijri",
+        "hijri:15",
+        "hijri:fatimid",
+        "hijri:habash",
+        "persian",
+        "ethiopian",
+        "coptic",
+        "indian",
+        "republican",
+    ] {
+        assert_eq!(name.parse::<DateSystem>().unwrap().to_string(), name);
+    }
+    assert_eq!(DateSystem::Hijri(HijriLeaps::Year16).to_string(), "hijri");
+}
+
+#[test]
+fn test_to_jdn_invalid_dates() {
+    assert!(to_jdn(Ymd::new(1900, 2, 29), DateSystem::Gregorian).is_err());
+    assert!(to_jdn(Ymd::new(1900, 2, 29), DateSystem::Julian).is_ok());
+    assert!(to_jdn(Ymd::new(1582, 10, 10), DateSystem::Reform).is_err());
+    assert!(to_jdn(Ymd::new(2025, 13, 1), DateSystem::Gregorian).is_err());
+    assert!(to_jdn(Ymd::new(2025, 4, 31), DateSystem::Gregorian).is_err());
+}
+
+#[test]
+fn test_out_of_range() {
+    assert!(to_jdn(Ymd::new(YEAR_LIMIT, 1, 1), DateSystem::Gregorian).is_ok());
+    assert!(to_jdn(Ymd::new(-YEAR_LIMIT - 1, 1, 1), DateSystem::Julian).is_err());
+    assert!(to_jdn(Ymd::new(i64::MIN, 1, 1), DateSystem::Gregorian).is_err());
+    assert_eq!(check_day_count(-DAY_LIMIT), Ok(-DAY_LIMIT));
+    assert!(check_day_count(i64::MAX).is_err());
+    assert!(check_day_count(i64::MIN).is_err());
+}
+
+#[test]
+fn test_from_jdn_round_trip() {
+    for jdn in (-10000..2600000).step_by(997) {
+        for system in [
+            DateSystem::Gregorian,
+            DateSystem::Julian,
+            DateSystem::Reform,
+            DateSystem::Hebrew,
+            DateSystem::Hijri(HijriLeaps::Year16),
+            DateSystem::Persian,
+            DateSystem::Ethiopian,
+            DateSystem::Coptic,
+            DateSystem::Indian,
+            DateSystem::Republican,
+        ] {
+            assert_eq!(to_jdn(from_jdn(jdn, system), system), Ok(jdn));
+        }
+    }
+}
+
+#[test]
+fn test_round_trip_at_day_limit() {
+    for jdn in [-DAY_LIMIT, DAY_LIMIT] {
+        for system in [
+            DateSystem::Gregorian,
+            DateSystem::Julian,
+            DateSystem::Hebrew,
+            DateSystem::Hijri(HijriLeaps::Year16),
+            DateSystem::Persian,
+            DateSystem::Ethiopian,
+            DateSystem::Indian,
+            DateSystem::Republican,
+        ] {
+            assert_eq!(
+                to_jdn(from_jdn(jdn, system), system),
+                Ok(jdn),
+                "{:?}",
+                system
+            );
+        }
+        jdn_printable(jdn, crate::maya::GMT_CORRELATION);
+    }
+}
+
+#[test]
+fn test_weekday() {
+    // 2025-03-14 was a Friday and 24 Ventôse 233, a quartidi
+    assert_eq!(DateSystem::Gregorian.weekday(2460749), 5);
+    assert_eq!(DateSystem::Republican.weekday(2460749), 3);
+    assert_eq!(DateSystem::Hebrew.week_length(), 7);
+    assert_eq!(DateSystem::Republican.week_length(), 10);
+}
+
+#[test]
+fn test_day_counts() {
+    assert_eq!(jdn_to_mjd(2400001), 0);
+    assert_eq!(jdn_to_mjd(2460749), 60748);
+    assert_eq!(mjd_to_jdn(60748), 2460749);
+    assert_eq!(jdn_to_rata_die(1721426), 1);
+    assert_eq!(jdn_to_rata_die(2460749), 739324);
+    assert_eq!(rata_die_to_jdn(739324), 2460749);
+}
+
+#[test]
+fn test_parse_ymd() {
+    assert_eq!("2025-03-14".parse(), Ok(Ymd::new(2025, 3, 14)));
+    assert_eq!("-4712-01-01".parse(), Ok(Ymd::new(-4712, 1, 1)));
+    assert!("2025/03/14".parse::<Ymd>().is_err());
+}
+
+#[test]
+fn test_jdn_printable() {
+    assert_eq!(
+        jdn_printable(2460749, crate::maya::GMT_CORRELATION),
+        [
+            "Gregorian 2025-03-14",
+            "Julian    2025-03-01",
+            "Hebrew    5785-12-14 (14 Adar 5785)",
+            "Hijri     1446-09-14 (14 Ramadan 1446)",
+            "Persian   1403-12-24 (24 Esfand 1403)",
+            "Ethiopian 2017-07-05 (5 Megabit 2017)",
+            "Coptic    1741-07-05 (5 Paremhat 1741)",
+            "Indian    1946-12-23 (23 Phalguna 1946)",
+            "French    0233-06-24 (24 Ventôse 233)",
+            "Japanese  令和7年03月14日",
+            "Maya      13.0.12.7.6 (11 Kimi 9 Kumk'u)",
+            "JDN       2460749",
+            "MJD       60748",
+            "Rata Die  739324",
+        ]
+    );
+}
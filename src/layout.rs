@@ -0,0 +1,92 @@
+use std::env;
+use std::str::FromStr;
+
+/// Layouts of the year grid, as months per row.
+static COLUMN_CHOICES: [usize; 6] = [1, 2, 3, 4, 6, 12];
+
+/// How many months are printed side by side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Columns {
+    /// the widest layout that fits the terminal
+    Auto,
+    Fixed(usize),
+}
+
+impl Columns {
+    /// Resolves the number of columns, measuring the terminal for `auto`.
+    ///
+    /// Falls back to the `COLUMNS` environment variable and then to 3 columns
+    /// when stdout is not a terminal.
+    pub fn resolve(self, month_width: usize) -> usize {
+        match self {
+            Columns::Fixed(columns) => columns,
+            Columns::Auto => {
+                let width = terminal_size::terminal_size()
+                    .map(|(w, _)| w.0 as usize)
+                    .or_else(|| env::var("COLUMNS").ok()?.parse().ok());
+                width.map_or(3, |width| fitting_columns(width, month_width))
+            }
+        }
+    }
+}
+
+impl FromStr for Columns {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "auto" {
+            return Ok(Columns::Auto);
+        }
+        s.parse::<usize>()
+            .ok()
+            .filter(|columns| COLUMN_CHOICES.contains(columns))
+            .map(Columns::Fixed)
+            .ok_or_else(|| {
+                format!(
+                    "invalid columns `{}`, expected auto, 1, 2, 3, 4, 6 or 12",
+                    s
+                )
+            })
+    }
+}
+
+/// The width of `columns` months of `month_width` characters separated by a space.
+pub fn grid_width(columns: usize, month_width: usize) -> usize {
+    (columns * (month_width + 1)).saturating_sub(1)
+}
+
+fn fitting_columns(width: usize, month_width: usize) -> usize {
+    COLUMN_CHOICES
+        .iter()
+        .copied()
+        .filter(|&columns| grid_width(columns, month_width) <= width)
+        .max()
+        .unwrap_or(1)
+}
+
+#[test]
+fn test_parse_columns() {
+    assert_eq!("auto".parse(), Ok(Columns::Auto));
+    assert_eq!("4".parse(), Ok(Columns::Fixed(4)));
+    assert_eq!("12".parse(), Ok(Columns::Fixed(12)));
+    assert!("5".parse::<Columns>().is_err());
+    assert!("0".parse::<Columns>().is_err());
+}
+
+#[test]
+fn test_fitting_columns() {
+    assert_eq!(fitting_columns(40, 21), 1);
+    assert_eq!(fitting_columns(80, 21), 3);
+    assert_eq!(fitting_columns(87, 21), 4);
+    assert_eq!(fitting_columns(86, 21), 3);
+    assert_eq!(fitting_columns(80, 24), 3);
+    assert_eq!(fitting_columns(200, 21), 6);
+    assert_eq!(fitting_columns(300, 21), 12);
+    assert_eq!(fitting_columns(10, 21), 1);
+}
+
+#[test]
+fn test_grid_width() {
+    assert_eq!(grid_width(3, 21), 65);
+    assert_eq!(grid_width(0, 21), 0);
+}
@@ -1,10 +1,52 @@
+mod chinese;
+mod color;
+mod coptic;
+mod era;
+mod fiscal;
+mod hebrew;
+mod highlight;
+mod hijri;
+mod indian;
+mod iso_week;
+mod jdn;
+mod layout;
 mod locale;
+mod maya;
+mod persian;
+mod quarter;
+mod republican;
+mod retail;
+mod today;
+mod week_scheme;
+mod weekend;
+mod year_numbering;
+
+pub use chinese::LunarDate;
+pub use color::ColorChoice;
+pub use fiscal::{parse_month, FiscalLabel, FiscalYear};
+pub use highlight::Highlights;
+pub use hijri::HijriLeaps;
+pub use iso_week::{from_iso_week_date, to_iso_week_date, IsoWeek};
+pub use jdn::{
+    check_day_count, display_jdn, from_jdn, jdn_to_mjd, jdn_to_rata_die, mjd_to_jdn,
+    rata_die_to_jdn, to_jdn, DateSystem, Ymd,
+};
+pub use layout::Columns;
+pub use maya::{MayaDate, GMT_CORRELATION};
+pub use quarter::{Half, Quarter};
+pub use retail::{RetailCalendar, RetailPattern, RetailPeriod};
+pub use today::today;
+pub use week_scheme::WeekScheme;
+pub use weekend::{parse_weekday, Weekend};
+pub use year_numbering::YearNumbering;
 
 use ansi_term::{
     Color::{Black, Cyan, Purple, Red, Yellow, RGB},
     Style,
 };
-use chrono::{Datelike, NaiveDate};
+use chrono::{Datelike, Duration, NaiveDate};
+use std::convert::TryFrom;
+use std::ops::Range;
 
 const REFORM_YEAR: u32 = 1099;
 
@@ -15,10 +57,124 @@ const MONTHS: usize = 12;
 const WEEKDAYS: u32 = 7;
 
 const COLUMN: usize = 3;
-const ROWS: usize = 4;
-const ROW_SIZE: usize = 7;
+const VERTICAL_WEEKS: u32 = 6;
+const MAX_MONTH_DAYS: u32 = 31;
+const ISO_WEEK_COLUMN_WIDTH: usize = 8;
+const FISCAL_WEEK_COLUMN_WIDTH: usize = 7;
 
 static TOKEN: &str = "\n";
+static DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Rendering options for [`display`].
+///
+/// New options are added over time, so start from [`Config::default`] and set the
+/// fields to change.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Config {
+    pub locale: String,
+    pub starting_day: u32,
+    pub color: bool,
+    pub week_numbers: bool,
+    pub weekend: Weekend,
+    pub highlights: Highlights,
+    /// the date to mark as today, or `None` to mark no date
+    pub today: Option<NaiveDate>,
+    /// months per row of the year grid
+    pub columns: usize,
+    /// print weeks as columns and weekdays as rows, like `ncal`
+    pub vertical: bool,
+    /// print the ordinal day of the year instead of the day of month, like `cal -j`
+    pub day_of_year: bool,
+    /// show the Julian Day Number of the first day of each month next to its name
+    pub show_jdn: bool,
+    /// label week numbers as ISO weeks, with their ISO year when it differs
+    pub iso_week: bool,
+    /// how the weeks of the week number column are counted
+    pub week_scheme: WeekScheme,
+    /// print the fiscal year starting at the given month instead of January to December
+    pub fiscal: Option<FiscalYear>,
+    /// print the periods of a retail year instead of months; weeks start on
+    /// `starting_day`, which should be the retail calendar's first weekday
+    pub retail: Option<RetailCalendar>,
+    /// head each quarter with its number and working days
+    pub quarters: bool,
+    /// print only the given quarter, 1 to 4, of the year
+    pub quarter: Option<u32>,
+    /// head each half-year with its number and working days
+    pub halves: bool,
+    /// print only the given half, 1 or 2, of the year
+    pub half: Option<u32>,
+    /// the calendar whose months are printed; Gregorian years are printed as the Gregorian
+    /// grid, the others month by month from their first day, and reform years not at all
+    pub date_system: DateSystem,
+    /// also print the day of month of this calendar in each day cell of the grid, and
+    /// the names of its months in the month headings
+    pub dual: Option<DateSystem>,
+    /// annotate each day with its Chinese lunar date, name the year by the sexagenary
+    /// cycle and highlight traditional festivals
+    pub lunar: bool,
+    /// name the year, and the months of years with an era change, by the Japanese era
+    pub era: bool,
+    /// how the years of Gregorian and reform calendars are numbered in the heading
+    pub year_numbering: YearNumbering,
+    /// annotate each day with its Tzolk'in number and each month with the Long Count of
+    /// its first day, under this correlation of the Maya calendar, see [`GMT_CORRELATION`]
+    pub maya: Option<i64>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            locale: String::new(),
+            starting_day: 0,
+            color: false,
+            week_numbers: false,
+            weekend: Weekend::default(),
+            highlights: Highlights::default(),
+            today: None,
+            columns: COLUMN,
+            vertical: false,
+            day_of_year: false,
+            show_jdn: false,
+            iso_week: false,
+            week_scheme: WeekScheme::default(),
+            fiscal: None,
+            retail: None,
+            quarters: false,
+            quarter: None,
+            halves: false,
+            half: None,
+            date_system: DateSystem::Gregorian,
+            dual: None,
+            lunar: false,
+            era: false,
+            year_numbering: YearNumbering::Common,
+            maya: None,
+        }
+    }
+}
+
+/// The width of a month printed by [`calendar`].
+pub fn month_width(config: &Config) -> usize {
+    let cells = if config.vertical {
+        VERTICAL_WEEKS
+    } else {
+        config.date_system.week_length()
+    };
+    let prefix = if config.vertical {
+        2
+    } else {
+        week_column_width(config)
+    };
+    prefix + cells as usize * cell_width(config)
+}
+
+/// Parses a `YYYY-MM-DD` date.
+pub fn parse_date(s: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(s.trim(), DATE_FORMAT)
+        .map_err(|_| format!("invalid date `{}`, expected YYYY-MM-DD", s.trim()))
+}
 
 fn is_leap_year(year: u32) -> bool {
     if year <= REFORM_YEAR {
@@ -67,220 +223,843 @@ fn get_days_accumulated_by_month(year: u32) -> (Vec<u32>, Vec<u32>) {
     let accum = days
         .iter()
         .scan(0, |acc, &x| {
-            *acc = *acc + x;
+            *acc += x;
             Some(*acc)
         })
         .collect();
     (accum, days)
 }
 
-fn first_day_printable(day_year: u32, starting_day: u32) -> String {
-    let mut printable = format!("");
+fn first_day_printable(position: u32, cell_width: usize, week_length: u32) -> String {
+    let mut printable = String::new();
 
-    if (day_year - starting_day) % WEEKDAYS == 0 {
-        printable.push_str("                  ");
+    if position % week_length == 0 {
+        printable.push_str(&" ".repeat((week_length as usize - 1) * cell_width));
     }
-    for i in 2..WEEKDAYS {
-        if (day_year - starting_day) % WEEKDAYS == i {
-            printable.push_str(&"   ".repeat(i as usize - 1));
+    for i in 2..week_length {
+        if position % week_length == i {
+            printable.push_str(&" ".repeat(cell_width * (i as usize - 1)));
             break;
         }
     }
     printable
 }
 
-fn remain_day_printable(day: u32, day_year: u32, starting_day: u32) -> String {
-    let base = if ((day_year - starting_day) % WEEKDAYS) == 0 {
-        format!("{:3}{}", day, TOKEN)
+fn remain_day_printable(
+    day: impl std::fmt::Display,
+    position: u32,
+    cell_width: usize,
+    week_length: u32,
+) -> String {
+    let base = if (position % week_length) == 0 {
+        format!("{:>w$}{}", day, TOKEN, w = cell_width)
     } else {
         String::default()
     };
 
-    let complement = (1..WEEKDAYS)
-        .find_map(|i| ((day_year - starting_day) % WEEKDAYS == i).then(|| format!("{:3}", day)))
+    let complement = (1..week_length)
+        .find_map(|i| (position % week_length == i).then(|| format!("{:>w$}", day, w = cell_width)))
         .unwrap_or_default();
 
     format!("{}{}", base, complement)
 }
 
+/// the [`week_rows`] lines of `days` days printed as `label(day)`, in weeks of
+/// [`DateSystem::week_length`] days with `first_offset` blank cells before the first day
 fn body_printable(
-    year: u32,
-    month: usize,
+    first_offset: u32,
     days: u32,
-    months_memoized: Vec<u32>,
-    year_memoized: u32,
-    starting_day: u32,
-    week_numbers: bool,
+    label: &dyn Fn(u32) -> String,
+    config: &Config,
 ) -> Vec<String> {
-    let mut result = Vec::<String>::new();
-    let mut result_days = format!("");
+    let mut result_days = String::new();
+    let week_length = config.date_system.week_length();
+    let cell_width = cell_width(config);
+    let width = week_length as usize * cell_width;
 
-    // display month formatted
+    // display month formatted, the position of a day counting the blank cells before it
     (1..days + 1).for_each(|day| {
+        let position = first_offset + day;
         if day == 1 {
-            let first_day = days_by_date(1, month, year, months_memoized.clone(), year_memoized);
-            result_days.push_str(&first_day_printable(first_day, starting_day))
+            result_days.push_str(&first_day_printable(position, cell_width, week_length))
         }
-        let day_year = days_by_date(day, month, year, months_memoized.clone(), year_memoized);
-        result_days.push_str(&remain_day_printable(day, day_year, starting_day))
+        result_days.push_str(&remain_day_printable(
+            label(day),
+            position,
+            cell_width,
+            week_length,
+        ))
     });
 
     // lines splitted by '\n' TOKEN
-    result_days
+    let mut result: Vec<String> = result_days
         .split(TOKEN)
-        .collect::<Vec<&str>>()
-        .into_iter()
-        .for_each(|i| result.push(i.to_string()));
+        .map(|line| format!("{:<w$}", line, w = width))
+        .collect();
+    // all bodies have the same lines, the last one of a month ending a week is empty
+    result.resize(week_rows(config) as usize, " ".repeat(width));
+    result
+}
 
-    for line in 0..result.len() {
-        let spaces =
-            21 - result[line].len() + (3 * (result[line].is_empty() && week_numbers) as usize);
-        result[line] += &" ".repeat(spaces);
-    }
-    // all bodies should have at least 7 lines
-    if result.len() < 7 {
-        result.push(" ".repeat(21 + (3 * week_numbers as usize)));
+/// the lines of a month of [`calendar`]: its title lines, the weekday names and the
+/// lines of [`body_printable`], led by the week number `week(y)` of each line
+#[allow(clippy::too_many_arguments)]
+fn month_printable(
+    title: &[String],
+    week_names: Vec<String>,
+    first_offset: u32,
+    days: u32,
+    label: &dyn Fn(u32) -> String,
+    week: &dyn Fn(u32) -> Option<String>,
+    config: &Config,
+) -> Vec<String> {
+    let cell_width = cell_width(config);
+    let week_width = week_column_width(config);
+    let prefix = " ".repeat(week_width);
+    let mut result: Vec<String> = title
+        .iter()
+        .map(|line| format!("{} {:^w$}", prefix, line, w = title_width(config)))
+        .collect();
+    result.push(format!(
+        "{}{}",
+        prefix,
+        circular_week_name(week_names, config.starting_day as usize, cell_width)
+    ));
+    let body = body_printable(first_offset, days, label, config);
+    for (y, line) in body.into_iter().enumerate() {
+        match week(y as u32).filter(|_| config.week_numbers) {
+            Some(week) => result.push(format!("{:>w$}{}", week, line, w = week_width)),
+            None => result.push(format!("{}{}", prefix, line)),
+        }
     }
     result
 }
 
-fn month_printable(
+/// the lines of the month name, annotated by [`annotated_title`]
+fn month_title(year: u32, month: usize, month_names: &[String], config: &Config) -> Vec<String> {
+    let name = month_names[month - 1].clone();
+    let days = days_by_month(year)[month];
+    // in years with an era change, months are labelled with the eras of their days
+    let era = (config.era && era::year_names(year as i32, &config.locale).len() > 1)
+        .then(|| NaiveDate::from_ymd_opt(year as i32, month as u32, 1))
+        .flatten()
+        .map(|first| {
+            let last = first + Duration::days(days as i64 - 1);
+            era::range_names(first, last, &config.locale).join("/")
+        })
+        .filter(|eras| !eras.is_empty());
+    match grid_jdn(year, month, 1) {
+        Ok(jdn) => annotated_title(name, era, jdn, days, month_names, config),
+        Err(_) => wrap_title(name, &[era], config),
+    }
+}
+
+/// the lines of a month name followed by `era`, the months of the secondary calendar its
+/// `days` from `jdn` fall in, and by the JDN of its first day with `show_jdn` and its
+/// Long Count with `maya`, see [`wrap_title`]
+fn annotated_title(
+    name: String,
+    era: Option<String>,
+    jdn: i64,
+    days: u32,
+    month_names: &[String],
+    config: &Config,
+) -> Vec<String> {
+    let annotations = [
+        era,
+        secondary_months(jdn, days, month_names, config).map(|months| format!("({})", months)),
+        config.show_jdn.then(|| format!("JD{}", jdn)),
+        config
+            .maya
+            .map(|correlation| MayaDate::from_jdn(jdn, correlation).to_string()),
+    ];
+    wrap_title(name, &annotations, config)
+}
+
+/// the lines of a title followed by its annotations, starting a new line for those that
+/// do not fit the width of the month
+fn wrap_title(name: String, annotations: &[Option<String>], config: &Config) -> Vec<String> {
+    let mut lines = vec![name];
+    for annotation in annotations.iter().flatten() {
+        let line = lines.last_mut().expect("titles start with the month name");
+        let annotated = format!("{} {}", line, annotation);
+        if annotated.chars().count() <= title_width(config) {
+            *line = annotated;
+        } else {
+            lines.push(annotation.clone());
+        }
+    }
+    lines
+}
+
+/// the names of the months of the `dual` calendar, or of the Chinese lunar months with
+/// `lunar`, that `days` days from `jdn` fall in, e.g. `Ramadan/Shawwal` or `M12/M1`
+fn secondary_months(
+    jdn: i64,
+    days: u32,
+    month_names: &[String],
+    config: &Config,
+) -> Option<String> {
+    let name = |jdn| match config.dual {
+        Some(system) => {
+            let date = from_jdn(jdn, system);
+            Some(
+                system
+                    .month_name(date.year, date.month, &config.locale)
+                    .unwrap_or_else(|| month_names[(date.month as usize - 1) % MONTHS].clone()),
+            )
+        }
+        None if config.lunar => LunarDate::from_jdn(jdn).map(|lunar| {
+            let leap = if lunar.leap { "L" } else { "M" };
+            format!("{}{}", leap, lunar.month)
+        }),
+        None => None,
+    };
+    let mut names: Vec<String> = Vec::new();
+    for name in (jdn..jdn + days as i64).filter_map(name) {
+        if names.last() != Some(&name) {
+            names.push(name);
+        }
+    }
+    (!names.is_empty()).then(|| names.join("/"))
+}
+
+/// the room for a month title above its day cells
+fn title_width(config: &Config) -> usize {
+    if config.vertical {
+        2 + VERTICAL_WEEKS as usize * cell_width(config)
+    } else {
+        config.date_system.week_length() as usize * cell_width(config) - 1
+    }
+}
+
+/// the JDN of a day of the grid, which follows the Julian leap year rule up to REFORM_YEAR
+fn grid_jdn(year: u32, month: usize, day: u32) -> Result<i64, String> {
+    let system = if year <= REFORM_YEAR {
+        DateSystem::Julian
+    } else {
+        DateSystem::Gregorian
+    };
+    to_jdn(Ymd::new(year as i64, month as u32, day), system)
+}
+
+/// the lines of weeks a month may span: 6 weeks of 7 days, or 4 décades
+fn week_rows(config: &Config) -> u32 {
+    let week_length = config.date_system.week_length();
+    (MAX_MONTH_DAYS + week_length - 1).div_ceil(week_length)
+}
+
+/// the width of a day cell, including its separating space
+fn cell_width(config: &Config) -> usize {
+    let width = if config.day_of_year { 4 } else { 3 };
+    // room for a `/` and the day of month of `dual` or the Tzolk'in number, or the lunar
+    // month, e.g. `/L6`
+    if config.dual.is_some() || config.maya.is_some() {
+        width + 3
+    } else if config.lunar {
+        width + 4
+    } else {
+        width
+    }
+}
+
+/// the text printed for a day: its day of month or, with `day_of_year`, its ordinal day,
+/// followed by its day of month in the `dual` calendar, e.g. `14/23`, by its Tzolk'in
+/// number with `maya`, or by its lunar day with `lunar`, where lunar months start with
+/// their number, e.g. `25/M6` or `25/L6` for a leap month
+fn day_label(
+    year: u32,
+    day: u32,
+    month: usize,
+    months_memoized: &[u32],
+    config: &Config,
+) -> String {
+    let label = if config.day_of_year {
+        months_memoized[month - 1] + day
+    } else {
+        day
+    };
+    if config.lunar {
+        return match grid_jdn(year, month, day)
+            .ok()
+            .and_then(LunarDate::from_jdn)
+        {
+            Some(lunar) if lunar.day == 1 => {
+                let leap = if lunar.leap { "L" } else { "M" };
+                format!("{}/{}{}", label, leap, lunar.month)
+            }
+            Some(lunar) => format!("{}/{}", label, lunar.day),
+            None => label.to_string(),
+        };
+    }
+    dual_label(label, grid_jdn(year, month, day).ok(), config)
+}
+
+/// `label` followed by the day of month of `jdn` in the `dual` calendar, e.g. `14/23`,
+/// or by its Tzolk'in number with `maya`, e.g. `14/11`
+fn dual_label(label: u32, jdn: Option<i64>, config: &Config) -> String {
+    match (config.dual, config.maya, jdn) {
+        (Some(system), _, Some(jdn)) => format!("{}/{}", label, from_jdn(jdn, system).day),
+        (None, Some(correlation), Some(jdn)) => format!(
+            "{}/{}",
+            label,
+            MayaDate::from_jdn(jdn, correlation).tzolkin_number
+        ),
+        _ => label.to_string(),
+    }
+}
+
+/// the weekday names of a week of any length, starting at `idx`
+fn circular_week_name(week_name: Vec<String>, idx: usize, cell_width: usize) -> String {
+    let row_size = week_name.len();
+    let padding = " ".repeat(cell_width - 2);
+    let mut s = padding.clone();
+    for i in idx..(row_size - 1 + idx) {
+        s.push_str(&format!("{}{}", week_name[i % row_size], padding));
+    }
+    s.push_str(week_name[(row_size - 1 + idx) % row_size].as_str());
+    s.to_string()
+}
+
+/// the week-numbering year and week of a line of days ending on the given day
+///
+/// Fiscal years number their weeks from their first day
+fn week_number(year: u32, month: usize, day: u32, config: &Config) -> Option<(i32, u32)> {
+    let date = NaiveDate::from_ymd_opt(year as i32, month as u32, day)?;
+    Some(match config.fiscal {
+        Some(fiscal) => (
+            fiscal.year_of(date) as i32,
+            fiscal.week_of(date, config.starting_day).1,
+        ),
+        None => config.week_scheme.week_of(date, config.starting_day),
+    })
+}
+
+/// the week number shown next to a line of days holding the given day
+///
+/// With `iso_week`, weeks belonging to another ISO year are labelled like `2026-W01`
+fn week_label(year: u32, month: usize, day: u32, config: &Config) -> Option<String> {
+    if let Some(fiscal) = config.fiscal {
+        let date = NaiveDate::from_ymd_opt(year as i32, month as u32, day)?;
+        let (quarter, week) = fiscal.week_of(date, config.starting_day);
+        return Some(format!("Q{} W{:02}", quarter, week));
+    }
+    let (week_year, week) = week_number(year, month, day, config)?;
+    Some(if !config.iso_week {
+        week.to_string()
+    } else if week_year != year as i32 {
+        IsoWeek::new(week_year, week)?.to_string()
+    } else {
+        format!("W{:02}", week)
+    })
+}
+
+/// the width of the week number column of horizontal months
+fn week_column_width(config: &Config) -> usize {
+    if !config.week_numbers {
+        0
+    } else if config.fiscal.is_some() {
+        FISCAL_WEEK_COLUMN_WIDTH
+    } else if config.iso_week {
+        ISO_WEEK_COLUMN_WIDTH
+    } else {
+        3
+    }
+}
+
+/// the day of a week line whose week number labels the line, given the day at each position
+///
+/// Lines start on `starting_day` while the scheme's weeks may start on another weekday, so
+/// a line may hold the end of one week and the start of the next. The day is taken from the
+/// week covering most of the line, or from any day shown when that week has none.
+fn week_line_day<T>(day_at: impl Fn(u32) -> Option<T>, config: &Config) -> Option<T> {
+    let starting_day = config.starting_day % WEEKDAYS;
+    let split = match config.fiscal {
+        Some(_) => 0,
+        None => config
+            .week_scheme
+            .first_day(starting_day)
+            .map_or(0, |first| (first + WEEKDAYS - starting_day) % WEEKDAYS),
+    };
+    let majority = if split > WEEKDAYS / 2 {
+        0..split
+    } else {
+        split..WEEKDAYS
+    };
+    majority.rev().chain((0..WEEKDAYS).rev()).find_map(day_at)
+}
+
+/// the day labelling the given week line of a month grid with its week number
+fn week_day_of_line(first_offset: u32, days: u32, y: u32, config: &Config) -> Option<u32> {
+    week_line_day(|x| get_day_at_position(first_offset, days, x, y), config)
+}
+
+fn vertical_month_printable(
     year: u32,
     month: usize,
     days: u32,
-    months_memoized: Vec<u32>,
-    year_memoized: u32,
-    starting_day: u32,
-    month_names: Vec<String>,
-    week_names: Vec<String>,
-    week_numbers: bool,
+    months_memoized: &[u32],
+    month_names: &[String],
+    week_names: &[String],
+    config: &Config,
 ) -> Vec<String> {
     let mut result = Vec::<String>::new();
-    let body = body_printable(
-        year,
-        month,
-        days,
-        months_memoized,
-        year_memoized,
-        starting_day,
-        week_numbers,
-    );
-    let month_name = &month_names[month - 1];
-    result.push(format!(" {:^20}", month_name));
-    let header = circular_week_name(week_names, starting_day as usize);
-    result.push(header);
+    let starting_day = config.starting_day;
+    let first_offset = get_first_offset(year, month as u32, starting_day);
+    let cell_width = cell_width(config);
+    for line in month_title(year, month, month_names, config) {
+        result.push(format!("{:^w$}", line, w = title_width(config)));
+    }
 
-    body.into_iter().for_each(|item| {
-        result.push(item);
-    });
+    // one line per weekday, one column per week
+    for x in 0..WEEKDAYS {
+        let mut line = week_names[((starting_day + x) % WEEKDAYS) as usize].clone();
+        for y in 0..VERTICAL_WEEKS {
+            match get_day_at_position(first_offset, days, x, y) {
+                Some(day) => line.push_str(&format!(
+                    "{:>w$}",
+                    day_label(year, day, month, months_memoized, config),
+                    w = cell_width
+                )),
+                None => line.push_str(&" ".repeat(cell_width)),
+            }
+        }
+        result.push(line);
+    }
+
+    if config.week_numbers {
+        let mut footer = "  ".to_string();
+        for y in 0..VERTICAL_WEEKS {
+            match week_day_of_line(first_offset, days, y, config)
+                .and_then(|day| week_number(year, month, day, config))
+            {
+                Some((_, week)) => footer.push_str(&format!("{:>w$}", week, w = cell_width)),
+                None => footer.push_str(&" ".repeat(cell_width)),
+            }
+        }
+        result.push(footer);
+    }
     result
 }
 
-fn circular_week_name(week_name: Vec<String>, idx: usize) -> String {
-    let mut s = " ".to_string();
-    for i in idx..(ROW_SIZE - 1 + idx) {
-        s.push_str(&format!("{} ", week_name[i % ROW_SIZE]));
+/// the lines of a retail period, titled by its number and first day, e.g. `P1 February 2`
+///
+/// The longest period, the last of a 53-week 4-4-5 year, has 6 weeks
+fn period_printable(
+    period: &RetailPeriod,
+    month_names: &[String],
+    week_names: Vec<String>,
+    config: &Config,
+) -> Vec<String> {
+    let title = format!(
+        "P{} {} {}",
+        period.number,
+        month_names[period.start.month0() as usize],
+        period.start.day()
+    );
+    let label = |day: u32| {
+        let date = period.start + Duration::days(day as i64 - 1);
+        if config.day_of_year {
+            date.ordinal().to_string()
+        } else {
+            date.day().to_string()
+        }
+    };
+    let week = |y: u32| (y < period.weeks).then(|| (period.first_week + y).to_string());
+    month_printable(
+        &[title],
+        week_names,
+        0,
+        period.weeks * WEEKDAYS,
+        &label,
+        &week,
+        config,
+    )
+}
+
+/// a month of a calendar other than the Gregorian one, see [`Config::date_system`]
+struct SystemMonth {
+    month: u32,
+    first: NaiveDate,
+    days: u32,
+    /// the day of the year of its first day
+    year_day: u32,
+    jdn: i64,
+    /// the day of the week of its first day, see [`DateSystem::weekday`]
+    weekday: u32,
+    week_length: u32,
+}
+
+impl SystemMonth {
+    /// the blank cells before its first day in a month grid with weeks starting on
+    /// `starting_day`
+    fn first_offset(&self, starting_day: u32) -> u32 {
+        let weekdays = self.week_length;
+        (self.weekday + weekdays - starting_day % weekdays) % weekdays
     }
-    s.push_str(week_name[(ROW_SIZE - 1 + idx) % ROW_SIZE].as_str());
-    s.to_string()
+
+    /// the day at the given position of a month grid with weeks starting on `starting_day`
+    fn date_at(&self, x: u32, y: u32, starting_day: u32) -> Option<NaiveDate> {
+        let weekdays = self.week_length;
+        let n = (y * weekdays + x).checked_sub(self.first_offset(starting_day))?;
+        (x < weekdays && n < self.days).then(|| self.first + Duration::days(n as i64))
+    }
+}
+
+/// whether months are printed from [`Config::date_system`] rather than the
+/// Gregorian grid
+fn uses_system_months(config: &Config) -> bool {
+    config.date_system != DateSystem::Gregorian
+}
+
+/// the months of `year` of [`Config::date_system`]
+///
+/// Fails for years whose days chrono cannot represent, about 262,000 years from year 0
+fn system_months(year: u32, config: &Config) -> Result<Vec<SystemMonth>, String> {
+    let system = config.date_system;
+    let year = year as i64;
+    let mut year_day = 1;
+    let mut months = Vec::new();
+    for month in system.months(year) {
+        let days = system.days_in_month(year, month);
+        let jdn = to_jdn(Ymd::new(year, month, 1), system)?;
+        let first = jdn_to_date(jdn).ok_or_else(|| {
+            format!(
+                "year {} of --calendar {} is out of range, its days cannot be printed",
+                year, system
+            )
+        })?;
+        months.push(SystemMonth {
+            month,
+            first,
+            days,
+            year_day,
+            jdn,
+            weekday: system.weekday(jdn),
+            week_length: system.week_length(),
+        });
+        year_day += days;
+    }
+    Ok(months)
 }
 
-pub fn calendar(
+/// the date of a Julian Day Number, if chrono can represent it
+fn jdn_to_date(jdn: i64) -> Option<NaiveDate> {
+    let days = i32::try_from(jdn_to_rata_die(jdn)).ok()?;
+    NaiveDate::from_num_days_from_ce_opt(days)
+}
+
+/// the lines of a month of [`Config::date_system`], numbering its days from 1
+fn system_month_printable(
     year: u32,
-    locale_str: &str,
-    starting_day: u32,
-    week_numbers: bool,
-) -> Vec<Vec<Vec<String>>> {
-    let mut rows: Vec<Vec<Vec<String>>> = vec![vec![Vec::new(); COLUMN]; ROWS];
-    let (months_memoized, months) = get_days_accumulated_by_month(year);
-    let year_memoized = days_by_year(year);
-    let locale_info = locale::LocaleInfo::new(locale_str);
+    month: &SystemMonth,
+    month_names: &[String],
+    week_names: Vec<String>,
+    config: &Config,
+) -> Vec<String> {
+    let name = config
+        .date_system
+        .month_name(year as i64, month.month, &config.locale)
+        .unwrap_or_else(|| month_names[(month.month as usize - 1) % MONTHS].clone());
+    let title = annotated_title(name, None, month.jdn, month.days, month_names, config);
+    let date_at = |x, y| month.date_at(x, y, config.starting_day);
+    let label = |day: u32| {
+        let label = if config.day_of_year {
+            month.year_day + day - 1
+        } else {
+            day
+        };
+        dual_label(label, Some(month.jdn + day as i64 - 1), config)
+    };
+    let week = |y| {
+        let day = week_line_day(|x| date_at(x, y), config)?;
+        week_label(day.year() as u32, day.month() as usize, day.day(), config)
+    };
+    month_printable(
+        &title,
+        week_names,
+        month.first_offset(config.starting_day),
+        month.days,
+        &label,
+        &week,
+        config,
+    )
+}
+
+pub fn calendar(year: u32, config: &Config) -> Result<Vec<Vec<Vec<String>>>, String> {
+    // the Gregorian grid has no room for the days skipped in October 1582
+    if config.date_system == DateSystem::Reform {
+        return Err(
+            "--calendar reform only converts dates with rusti-cal jdn or --dual, print the year with --calendar gregorian or julian"
+                .to_string(),
+        );
+    }
+    let Config {
+        ref locale,
+        starting_day,
+        columns,
+        vertical,
+        ..
+    } = *config;
+    let count = if uses_system_months(config) {
+        config.date_system.months(year as i64).len()
+    } else {
+        MONTHS
+    };
+    let shown = shown_months(count, config);
+    let mut rows: Vec<Vec<Vec<String>>> = vec![Vec::new(); shown.len().div_ceil(columns)];
+    let locale_info = locale::LocaleInfo::new(locale);
     let month_names = locale_info.month_names();
-    let week_names = locale_info.week_day_names();
-    for month in 1..=MONTHS {
-        let row_idx = (month - 1) / COLUMN;
-        let col_idx = (month - 1) % COLUMN;
+    let week_names = config
+        .date_system
+        .week_day_names()
+        .unwrap_or_else(|| locale_info.week_day_names());
+    if let Some(retail) = config.retail {
+        for (idx, period) in retail.periods(year as i32).iter().enumerate() {
+            if !shown.contains(&idx) {
+                continue;
+            }
+            rows[(idx - shown.start) / columns].push(period_printable(
+                period,
+                &month_names,
+                week_names.clone(),
+                config,
+            ));
+        }
+        return Ok(align_titles(rows, config));
+    }
+    if uses_system_months(config) {
+        for (idx, month) in system_months(year, config)?.iter().enumerate() {
+            if !shown.contains(&idx) {
+                continue;
+            }
+            rows[(idx - shown.start) / columns].push(system_month_printable(
+                year,
+                month,
+                &month_names,
+                week_names.clone(),
+                config,
+            ));
+        }
+        return Ok(align_titles(rows, config));
+    }
+    for (idx, (year, month)) in year_months(year, config).into_iter().enumerate() {
+        if !shown.contains(&idx) {
+            continue;
+        }
+        let (months_memoized, months) = get_days_accumulated_by_month(year);
+        let days = months[month];
+        if vertical {
+            let printable = vertical_month_printable(
+                year,
+                month,
+                days,
+                &months_memoized,
+                &month_names,
+                &week_names,
+                config,
+            );
+            rows[(idx - shown.start) / columns].push(printable);
+            continue;
+        }
 
-        let mut printable = month_printable(
-            year,
-            month,
-            months[month],
-            months_memoized.clone(),
-            year_memoized,
-            starting_day,
-            month_names.clone(),
+        let first_offset = get_first_offset(year, month as u32, starting_day);
+        let title = month_title(year, month, &month_names, config);
+        let label = |day| day_label(year, day, month, &months_memoized, config);
+        let week = |y| {
+            week_day_of_line(first_offset, days, y, config)
+                .and_then(|day| week_label(year, month, day, config))
+        };
+        let printable = month_printable(
+            &title,
             week_names.clone(),
-            week_numbers,
+            first_offset,
+            days,
+            &label,
+            &week,
+            config,
         );
+        rows[(idx - shown.start) / columns].push(printable);
+    }
 
-        if week_numbers {
-            for (line_idx, line) in printable.iter_mut().enumerate() {
-                if line_idx < 2 {
-                    *line = format!("   {}", line);
-                } else if !line.trim().is_empty() {
-                    if let Some(last_day_str) = line.split_whitespace().last() {
-                        if let Ok(last_day) = last_day_str.parse::<u32>() {
-                            if let Some(date) =
-                                NaiveDate::from_ymd_opt(year as i32, month as u32, last_day)
-                            {
-                                let week_num = date.iso_week().week();
-                                *line = format!(
-                                    "{}{}{}",
-                                    " ".repeat(1 + (week_num < 10) as usize),
-                                    week_num,
-                                    line
-                                );
-                            }
-                        }
-                    }
-                }
-            }
-        }
+    Ok(align_titles(rows, config))
+}
 
-        rows[row_idx][col_idx] = printable;
+/// the lines of a month below its title, see [`month_printable`] and
+/// [`vertical_month_printable`]
+fn body_lines(config: &Config) -> usize {
+    if config.vertical {
+        WEEKDAYS as usize + config.week_numbers as usize
+    } else {
+        1 + week_rows(config) as usize
     }
+}
 
+/// pads the titles of the months of each row with blank lines to the tallest one, so
+/// that their days line up
+fn align_titles(mut rows: Vec<Vec<Vec<String>>>, config: &Config) -> Vec<Vec<Vec<String>>> {
+    let body = body_lines(config);
+    let blank = " ".repeat(month_width(config));
+    for row in rows.iter_mut() {
+        let height = row
+            .iter()
+            .map(|month| month.len() - body)
+            .max()
+            .unwrap_or(0);
+        for month in row.iter_mut() {
+            let title = month.len() - body;
+            month.splice(title..title, vec![blank.clone(); height - title]);
+        }
+    }
     rows
 }
 
+/// the indices of the months printed, all `count` months or those of `quarter` or `half`
+fn shown_months(count: usize, config: &Config) -> Range<usize> {
+    match (config.quarter, config.half) {
+        (Some(quarter), _) => {
+            let first = (quarter as usize - 1) * 3;
+            first..first + 3
+        }
+        (None, Some(half)) => {
+            let first = (half as usize - 1) * 6;
+            first..first + 6
+        }
+        (None, None) => 0..count,
+    }
+}
+
+/// the quarter, first and last day of each month, or of each period of a retail year
+fn month_bounds(year: u32, config: &Config) -> Vec<(u32, NaiveDate, NaiveDate)> {
+    if let Some(retail) = config.retail {
+        return retail
+            .periods(year as i32)
+            .iter()
+            .map(|period| (period.quarter(), period.start, period.end()))
+            .collect();
+    }
+    year_months(year, config)
+        .into_iter()
+        .enumerate()
+        .filter_map(|(idx, (year, month))| {
+            let first = NaiveDate::from_ymd_opt(year as i32, month as u32, 1)?;
+            let last = first + Duration::days(days_by_month(year)[month] as i64 - 1);
+            Some((idx as u32 / 3 + 1, first, last))
+        })
+        .collect()
+}
+
+/// the line heading the quarters that start among `count` months of a row starting
+/// at month `first`, with the number of working days of each quarter
+///
+/// Returns `None` when no quarter starts in the row
+fn quarter_heading(
+    first: usize,
+    count: usize,
+    bounds: &[(u32, NaiveDate, NaiveDate)],
+    config: &Config,
+) -> Option<String> {
+    group_heading(first, count, bounds, 'Q', &|quarter| quarter, config)
+}
+
+/// the line heading the half-years that start in a row, like [`quarter_heading`]
+fn half_heading(
+    first: usize,
+    count: usize,
+    bounds: &[(u32, NaiveDate, NaiveDate)],
+    config: &Config,
+) -> Option<String> {
+    group_heading(
+        first,
+        count,
+        bounds,
+        'H',
+        &|quarter| quarter.div_ceil(2),
+        config,
+    )
+}
+
+/// the line heading the groups of months, e.g. quarters, that start among `count`
+/// months of a row starting at month `first`, where `group` gives the group of a
+/// month from its quarter and `letter` prefixes the group number
+fn group_heading(
+    first: usize,
+    count: usize,
+    bounds: &[(u32, NaiveDate, NaiveDate)],
+    letter: char,
+    group: &dyn Fn(u32) -> u32,
+    config: &Config,
+) -> Option<String> {
+    let width = month_width(config);
+    let mut line = String::new();
+    let mut c = 0;
+    while c < count {
+        let idx = first + c;
+        let (quarter, from, _) = *bounds.get(idx)?;
+        let number = group(quarter);
+        if idx == 0 || group(bounds[idx - 1].0) != number {
+            let months = bounds[idx..]
+                .iter()
+                .take_while(|b| group(b.0) == number)
+                .count();
+            let span = months.min(count - c);
+            let to = bounds[idx + months - 1].2;
+            let label = format!(
+                "{}{} ({} working days)",
+                letter,
+                number,
+                config.weekend.working_days(from, to)
+            );
+            line.push_str(&format!(
+                "{:^w$} ",
+                label,
+                w = layout::grid_width(span, width)
+            ));
+            c += span;
+        } else {
+            line.push_str(&" ".repeat(width + 1));
+            c += 1;
+        }
+    }
+    (!line.trim().is_empty()).then_some(line)
+}
+
+/// the calendar year and month of each month printed for `year`, which names
+/// a fiscal year with `fiscal`
+fn year_months(year: u32, config: &Config) -> Vec<(u32, usize)> {
+    match config.fiscal {
+        Some(fiscal) => fiscal.months(year),
+        None => (1..=MONTHS).map(|month| (year, month)).collect(),
+    }
+}
+
+/// prints a line of a month, styling its first `prefix` characters and then each
+/// cell of `cell_width` characters, whose leading space stays unstyled
 fn print_row(
     row: &str,
-    starting_day: u32,
-    today_included: bool,
-    pos_today: u32,
-    monochromatic: bool,
-    week_numbers: bool,
+    color: bool,
+    prefix: usize,
+    prefix_style: Style,
+    cell_width: usize,
+    cell_styles: &[Style],
 ) {
-    let pos_saturday = (((6 - starting_day as i32) % 7) + 7) % 7 + (week_numbers as i32);
-    let pos_sunday = (((7 - starting_day as i32) % 7) + 7) % 7 + (week_numbers as i32);
-
-    let char_saturday = (1 + 3 * pos_saturday) as usize;
-    let char_sunday = (1 + 3 * pos_sunday) as usize;
-    let char_today = (1 + 3 * (pos_today + week_numbers as u32)) as usize;
+    if !color {
+        print!("{} ", row);
+        return;
+    }
 
     let row = row
         .split("")
         .filter(|s| !s.is_empty())
         .enumerate()
         .map(|(i, s)| {
-            if monochromatic {
-                if today_included && (i == char_today || i == char_today + 1) {
-                    Black.on(RGB(200, 200, 200)).paint(s)
-                } else {
-                    ansi_term::Style::default().paint(s)
-                }
-            } else {
-                if today_included && (i == char_today || i == char_today + 1) {
-                    Black.on(RGB(200, 200, 200)).paint(s)
-                } else if i == char_saturday || i == char_saturday + 1 {
-                    Yellow.bold().paint(s)
-                } else if i == char_sunday || i == char_sunday + 1 {
-                    Red.bold().paint(s)
-                } else if week_numbers && i < 3 {
-                    Purple.bold().paint(s)
-                } else {
-                    ansi_term::Style::default().paint(s)
-                }
+            if i < prefix {
+                return prefix_style.paint(s);
+            }
+            let offset = i - prefix;
+            match cell_styles.get(offset / cell_width) {
+                Some(style) if offset % cell_width != 0 => style.paint(s),
+                _ => Style::default().paint(s),
             }
         })
         .collect::<Vec<ansi_term::ANSIString>>();
@@ -288,79 +1067,277 @@ fn print_row(
     print!("{} ", ansi_term::ANSIStrings(&row));
 }
 
-/// calculates the positions of the given day within the overall grid
-///
-/// Returns a tuple
-///
-/// (month row, month column, day x position, line of month)
-fn get_today_position(year: u32, month: u32, day: u32, starting_day: u32) -> (u32, u32, u32, u32) {
+fn weekend_style(weekend: Weekend, weekday: u32) -> Style {
+    if weekend.is_last_day(weekday) {
+        Red.bold()
+    } else if weekend.contains(weekday) {
+        Yellow.bold()
+    } else {
+        Style::default()
+    }
+}
+
+/// the weekend style of a column of the grid, where décadi is the day of rest of
+/// a 10-day décade
+fn column_style(config: &Config, weekday: u32) -> Style {
+    let week_length = config.date_system.week_length();
+    if week_length == WEEKDAYS {
+        weekend_style(config.weekend, weekday)
+    } else if weekday % week_length == week_length - 1 {
+        Red.bold()
+    } else {
+        Style::default()
+    }
+}
+
+/// the style of a day marked as today or highlighted, if any
+fn day_style(config: &Config, date: NaiveDate) -> Option<Style> {
+    if Some(date) == config.today {
+        Some(Black.on(RGB(200, 200, 200)))
+    } else if config.highlights.contains(date) {
+        Some(config.highlights.style())
+    } else if config.lunar && lunar_festival(date).is_some() {
+        Some(Yellow.on(Red))
+    } else {
+        None
+    }
+}
+
+/// the traditional Chinese festival falling on a day, if any
+fn lunar_festival(date: NaiveDate) -> Option<&'static str> {
+    let jdn = rata_die_to_jdn(date.num_days_from_ce() as i64);
+    LunarDate::from_jdn(jdn)?.festival()
+}
+
+/// calculates the number of blank cells before the first day of the month
+fn get_first_offset(year: u32, month: u32, starting_day: u32) -> u32 {
     let (months_memoized, _) = get_days_accumulated_by_month(year);
 
     let first_of_month = days_by_date(1, month as usize, year, months_memoized, days_by_year(year));
-    let first_offset = (first_of_month - starting_day - 1) % WEEKDAYS;
+    (first_of_month - starting_day - 1) % WEEKDAYS
+}
 
-    let row_index = (month - 1) / 3;
-    let col_index = (month - 1) % 3;
+/// finds the day of month shown at the given position of a month grid
+///
+/// Returns `None` for the blank cells before the first and after the last day
+fn get_day_at_position(first_offset: u32, days: u32, x: u32, y: u32) -> Option<u32> {
+    let absolute_pos = y * WEEKDAYS + x + 1;
+    (absolute_pos > first_offset && absolute_pos - first_offset <= days)
+        .then(|| absolute_pos - first_offset)
+}
 
-    let absolute_pos = first_offset + day - 1;
-    let x = absolute_pos % 7;
-    let y = absolute_pos / 7;
+/// styles a line of a month as printed by [`calendar`], whose day cells show
+/// `date_at(x, y)`
+///
+/// Returns the style of the line prefix and of each day cell
+fn row_styles(
+    config: &Config,
+    line: usize,
+    date_at: &dyn Fn(u32, u32) -> Option<NaiveDate>,
+) -> (Style, Vec<Style>) {
+    let day_style = |x, y| date_at(x, y).and_then(|date| day_style(config, date));
 
-    (row_index, col_index, x, y)
+    if config.vertical {
+        // lines after the weekdays hold the week numbers
+        let x = line as u32 - 1;
+        if x >= WEEKDAYS {
+            return (Purple.bold(), vec![Purple.bold(); VERTICAL_WEEKS as usize]);
+        }
+        let style = weekend_style(config.weekend, config.starting_day + x);
+        let cells = (0..VERTICAL_WEEKS)
+            .map(|y| day_style(x, y).unwrap_or(style))
+            .collect();
+        (style, cells)
+    } else {
+        let cells = (0..config.date_system.week_length())
+            .map(|x| {
+                (line as u32)
+                    .checked_sub(2)
+                    .and_then(|y| day_style(x, y))
+                    .unwrap_or_else(|| column_style(config, config.starting_day + x))
+            })
+            .collect();
+        (Purple.bold(), cells)
+    }
 }
 
-pub fn display(
-    year: u32,
-    locale_str: &str,
-    starting_day: u32,
-    monochromatic: bool,
-    week_numbers: bool,
-) {
-    let rows = calendar(year, locale_str, starting_day, week_numbers);
-
-    let today = {
-        let now = chrono::Local::now();
-        (now.year() as u32, now.month(), now.day())
+pub fn display(year: u32, config: &Config) -> Result<(), String> {
+    let Config {
+        color,
+        columns,
+        vertical,
+        ..
+    } = *config;
+    let rows = calendar(year, config)?;
+    let system_months = if uses_system_months(config) {
+        Some(system_months(year, config)?)
+    } else {
+        None
     };
+    let shown = shown_months(
+        system_months.as_ref().map_or(MONTHS, |months| months.len()),
+        config,
+    );
+    let bounds = month_bounds(year, config);
 
-    let t_pos =
-        (today.0 == year).then(|| get_today_position(today.0, today.1, today.2, starting_day));
+    let year_months = year_months(year, config);
+    let days: Vec<u32> = year_months
+        .iter()
+        .map(|&(year, month)| days_by_month(year)[month])
+        .collect();
+    let offsets: Vec<u32> = year_months
+        .iter()
+        .map(|&(year, month)| get_first_offset(year, month as u32, config.starting_day))
+        .collect();
 
     // print the year
+    let periods = config.retail.map(|retail| retail.periods(year as i32));
+    let name = match (config.retail, config.fiscal) {
+        (Some(retail), _) => format!(
+            "FY{} ({})",
+            config.year_numbering.number(year as i64),
+            retail.pattern
+        ),
+        (None, Some(fiscal)) => fiscal.name(year, config.year_numbering),
+        (None, None) => {
+            let mut name = match config.date_system {
+                DateSystem::Gregorian | DateSystem::Reform => {
+                    config.year_numbering.year_name(year as i64, &config.locale)
+                }
+                system => system.year_name(year as i64),
+            };
+            if config.lunar {
+                name = format!(
+                    "{} {}",
+                    name,
+                    chinese::year_name(year as i32, &config.locale)
+                );
+            }
+            if config.era {
+                name = format!(
+                    "{} {}",
+                    name,
+                    era::year_names(year as i32, &config.locale).join("/")
+                );
+            }
+            name
+        }
+    };
+    let name = match (config.quarter, config.half) {
+        (Some(quarter), _) => format!("{} Q{}", name, quarter),
+        (None, Some(half)) => format!("{} H{}", name, half),
+        (None, None) => name,
+    };
+    let title = format!(
+        "{:^width$}",
+        name,
+        width = layout::grid_width(columns.min(shown.len()), month_width(config))
+    );
     println!(
-        "{}{}",
-        " ".repeat(6 * week_numbers as usize),
-        Style::new().bold().paint(format!(" {:^63}", year))
+        "{}",
+        if color {
+            Style::new().bold().paint(title).to_string()
+        } else {
+            title
+        }
     );
 
+    let prefix = if vertical {
+        2
+    } else {
+        week_column_width(config)
+    };
+
     for (r, row) in rows.iter().enumerate() {
-        for line in 0..8 {
+        let first = shown.start + r * columns;
+        let headings = [
+            config
+                .halves
+                .then(|| half_heading(first, row.len(), &bounds, config)),
+            config
+                .quarters
+                .then(|| quarter_heading(first, row.len(), &bounds, config)),
+        ];
+        for heading in headings.iter().flatten().flatten() {
+            if color {
+                println!("{}", Style::new().bold().paint(heading));
+            } else {
+                println!("{}", heading);
+            }
+        }
+        // the title lines of the row, padded by align_titles
+        let title = row[0].len() - body_lines(config);
+        for line in 0..row[0].len() {
             for (c, month) in row.iter().enumerate() {
-                if line == 0 {
-                    if monochromatic {
-                        print!("{} ", &month[line]);
-                    } else {
+                if line < title {
+                    if color {
                         print!("{} ", Cyan.bold().paint(&month[line]));
+                    } else {
+                        print!("{} ", &month[line]);
                     }
                 } else {
-                    let (today_included, x) = t_pos
-                        .filter(|p| p.0 == r as u32 && p.1 == c as u32 && p.3 + 2 == line as u32)
-                        .map(|p| (true, p.2))
-                        .unwrap_or((false, 0));
-
+                    let idx = first + c;
+                    let date_at = |x, y| match (&periods, &system_months) {
+                        (Some(periods), _) => periods[idx].date_at(x, y),
+                        (None, Some(months)) => months[idx].date_at(x, y, config.starting_day),
+                        (None, None) => {
+                            let (year, month) = year_months[idx];
+                            get_day_at_position(offsets[idx], days[idx], x, y).and_then(|day| {
+                                NaiveDate::from_ymd_opt(year as i32, month as u32, day)
+                            })
+                        }
+                    };
+                    let (prefix_style, cell_styles) =
+                        row_styles(config, line + 1 - title, &date_at);
                     print_row(
                         &month[line],
-                        starting_day,
-                        today_included,
-                        x,
-                        monochromatic,
-                        week_numbers,
+                        color,
+                        prefix,
+                        prefix_style,
+                        cell_width(config),
+                        &cell_styles,
                     );
                 }
             }
             println!();
         }
     }
+    Ok(())
+}
+
+/// prints the seven days of an ISO week, Monday first
+pub fn display_week(week: IsoWeek, config: &Config) {
+    let locale_info = locale::LocaleInfo::new(&config.locale);
+    let days = week.days();
+    let title = format!(" {:^20}", week.to_string());
+    let header = circular_week_name(locale_info.week_day_names(), 1, 3);
+    let line: String = days.iter().map(|d| format!("{:3}", d.day())).collect();
+
+    let weekend_styles: Vec<Style> = (1..=WEEKDAYS)
+        .map(|weekday| weekend_style(config.weekend, weekday))
+        .collect();
+    let day_styles: Vec<Style> = days
+        .iter()
+        .zip(&weekend_styles)
+        .map(|(d, &style)| day_style(config, *d).unwrap_or(style))
+        .collect();
+
+    if config.color {
+        println!("{}", Cyan.bold().paint(title));
+    } else {
+        println!("{}", title);
+    }
+    print_row(
+        &header,
+        config.color,
+        0,
+        Style::default(),
+        3,
+        &weekend_styles,
+    );
+    println!();
+    print_row(&line, config.color, 0, Style::default(), 3, &day_styles);
+    println!();
 }
 
 #[test]
@@ -369,31 +1346,31 @@ fn test_circular_week_name() {
     let locale_info = locale::LocaleInfo::new(locale_str);
     let week_name = locale_info.week_day_names();
     assert_eq!(
-        circular_week_name(week_name.clone(), 0),
+        circular_week_name(week_name.clone(), 0, 3),
         " Su Mo Tu We Th Fr Sa"
     );
     assert_eq!(
-        circular_week_name(week_name.clone(), 1),
+        circular_week_name(week_name.clone(), 1, 3),
         " Mo Tu We Th Fr Sa Su"
     );
     assert_eq!(
-        circular_week_name(week_name.clone(), 2),
+        circular_week_name(week_name.clone(), 2, 3),
         " Tu We Th Fr Sa Su Mo"
     );
     assert_eq!(
-        circular_week_name(week_name.clone(), 3),
+        circular_week_name(week_name.clone(), 3, 3),
         " We Th Fr Sa Su Mo Tu"
     );
     assert_eq!(
-        circular_week_name(week_name.clone(), 4),
+        circular_week_name(week_name.clone(), 4, 3),
         " Th Fr Sa Su Mo Tu We"
     );
     assert_eq!(
-        circular_week_name(week_name.clone(), 5),
+        circular_week_name(week_name.clone(), 5, 3),
         " Fr Sa Su Mo Tu We Th"
     );
     assert_eq!(
-        circular_week_name(week_name.clone(), 6),
+        circular_week_name(week_name.clone(), 6, 3),
         " Sa Su Mo Tu We Th Fr"
     );
 }
@@ -403,7 +1380,7 @@ fn test_circular_week_name_pt_br() {
     let locale_str = "pt_BR";
     let locale_info = locale::LocaleInfo::new(locale_str);
     let week_name = locale_info.week_day_names();
-    assert_eq!(circular_week_name(week_name, 0), " Do Se Te Qu Qu Se Sá");
+    assert_eq!(circular_week_name(week_name, 0, 3), " Do Se Te Qu Qu Se Sá");
 }
 
 #[test]
@@ -548,9 +1525,424 @@ fn test_get_days_accumulated_by_month() {
 
 #[test]
 fn test_remain_day_printable() {
-    assert_eq!(remain_day_printable(1, 1, 1), "  1\n");
-    assert_eq!(remain_day_printable(1, 2, 1), "  1");
-    assert_eq!(remain_day_printable(2, 2, 1), "  2");
-    assert_eq!(remain_day_printable(31, 31, 1), " 31");
-    assert_eq!(remain_day_printable(31, 31, 7), " 31");
+    assert_eq!(remain_day_printable(1, 0, 3, 7), "  1\n");
+    assert_eq!(remain_day_printable(1, 1, 3, 7), "  1");
+    assert_eq!(remain_day_printable(2, 1, 3, 7), "  2");
+    assert_eq!(remain_day_printable(31, 30, 3, 7), " 31");
+    assert_eq!(remain_day_printable(31, 24, 3, 7), " 31");
+    // decades end on their tenth day
+    assert_eq!(remain_day_printable(10, 10, 3, 10), " 10\n");
+    assert_eq!(remain_day_printable(7, 7, 3, 10), "  7");
+}
+
+#[test]
+fn test_get_day_at_position() {
+    // March 2025 starts on a Saturday
+    let first_offset = get_first_offset(2025, 3, 0);
+    assert_eq!(first_offset, 6);
+    assert_eq!(get_day_at_position(first_offset, 31, 5, 0), None);
+    assert_eq!(get_day_at_position(first_offset, 31, 6, 0), Some(1));
+    assert_eq!(get_day_at_position(first_offset, 31, 0, 1), Some(2));
+    assert_eq!(get_day_at_position(first_offset, 31, 1, 5), Some(31));
+    assert_eq!(get_day_at_position(first_offset, 31, 2, 5), None);
+    assert_eq!(get_first_offset(2025, 3, 1), 5);
+}
+
+#[test]
+fn test_vertical_month_printable() {
+    let locale_info = locale::LocaleInfo::new("en_US");
+    let month_names = locale_info.month_names();
+    let week_names = locale_info.week_day_names();
+    let config = Config {
+        starting_day: 1,
+        week_numbers: true,
+        vertical: true,
+        ..Config::default()
+    };
+    let (months_memoized, _) = get_days_accumulated_by_month(2025);
+    let printable = vertical_month_printable(
+        2025,
+        3,
+        31,
+        &months_memoized,
+        &month_names,
+        &week_names,
+        &config,
+    );
+    assert_eq!(
+        printable,
+        [
+            "       March        ",
+            "Mo     3 10 17 24 31",
+            "Tu     4 11 18 25   ",
+            "We     5 12 19 26   ",
+            "Th     6 13 20 27   ",
+            "Fr     7 14 21 28   ",
+            "Sa  1  8 15 22 29   ",
+            "Su  2  9 16 23 30   ",
+            "    9 10 11 12 13 14",
+        ]
+    );
+}
+
+#[test]
+fn test_body_printable_day_of_year() {
+    let config = Config {
+        day_of_year: true,
+        ..Config::default()
+    };
+    let (months_memoized, _) = get_days_accumulated_by_month(2024);
+    let label = |day| day_label(2024, day, 3, &months_memoized, &config);
+    let body = body_printable(get_first_offset(2024, 3, 0), 31, &label, &config);
+    assert_eq!(body[0], "                      61  62");
+    assert_eq!(body[1], "  63  64  65  66  67  68  69");
+    assert_eq!(body[4], "  84  85  86  87  88  89  90");
+    assert_eq!(body[5], "  91                        ");
+}
+
+#[test]
+fn test_week_label() {
+    let config = Config {
+        week_numbers: true,
+        ..Config::default()
+    };
+    let iso_config = Config {
+        iso_week: true,
+        ..config.clone()
+    };
+    assert_eq!(week_label(2025, 12, 27, &config), Some("52".to_string()));
+    assert_eq!(week_label(2025, 12, 31, &config), Some("1".to_string()));
+    assert_eq!(
+        week_label(2025, 12, 27, &iso_config),
+        Some("W52".to_string())
+    );
+    assert_eq!(
+        week_label(2025, 12, 31, &iso_config),
+        Some("2026-W01".to_string())
+    );
+    assert_eq!(
+        week_label(2021, 1, 2, &iso_config),
+        Some("2020-W53".to_string())
+    );
+    let us_config = Config {
+        week_scheme: WeekScheme::Us,
+        ..config.clone()
+    };
+    assert_eq!(week_label(2025, 1, 4, &us_config), Some("1".to_string()));
+    assert_eq!(week_label(2025, 1, 11, &us_config), Some("2".to_string()));
+    assert_eq!(week_label(2025, 12, 31, &us_config), Some("1".to_string()));
+    let fiscal_config = Config {
+        fiscal: Some(FiscalYear::new(4, FiscalLabel::Span)),
+        ..config.clone()
+    };
+    assert_eq!(
+        week_label(2025, 4, 5, &fiscal_config),
+        Some("Q1 W01".to_string())
+    );
+    assert_eq!(
+        week_label(2026, 1, 3, &fiscal_config),
+        Some("Q4 W40".to_string())
+    );
+}
+
+#[test]
+fn test_week_day_of_line() {
+    // January 2025 starts on a Wednesday, the third column of weeks starting on Monday
+    let first_offset = get_first_offset(2025, 1, 1);
+    let config = Config {
+        starting_day: 1,
+        week_numbers: true,
+        week_scheme: WeekScheme::Us,
+        ..Config::default()
+    };
+    // US weeks start on Sunday, so the line of January 1 to 5 is labelled by January 4
+    assert_eq!(week_day_of_line(first_offset, 31, 0, &config), Some(4));
+    assert_eq!(week_label(2025, 1, 4, &config), Some("1".to_string()));
+    // ISO weeks match the lines
+    let config = Config {
+        week_scheme: WeekScheme::Iso,
+        ..config
+    };
+    assert_eq!(week_day_of_line(first_offset, 31, 0, &config), Some(5));
+    // a line of Sunday to Saturday is labelled by the ISO week of Monday to Saturday
+    let first_offset = get_first_offset(2025, 1, 0);
+    let config = Config {
+        starting_day: 0,
+        ..config
+    };
+    assert_eq!(week_day_of_line(first_offset, 31, 1, &config), Some(11));
+}
+
+#[test]
+fn test_period_printable() {
+    let config = Config {
+        week_numbers: true,
+        ..Config::default()
+    };
+    let locale_info = locale::LocaleInfo::new("en_US");
+    let period = RetailCalendar::default().periods(2023)[1];
+    let printable = period_printable(
+        &period,
+        &locale_info.month_names(),
+        locale_info.week_day_names(),
+        &config,
+    );
+    assert_eq!(printable.len(), 8);
+    assert_eq!(printable[0], "       P2 February 26   ");
+    assert_eq!(printable[2], "  5 26 27 28  1  2  3  4");
+    assert_eq!(printable[6], "  9 26 27 28 29 30 31  1");
+    assert_eq!(printable[7], " ".repeat(24));
+}
+
+#[test]
+fn test_quarter_heading() {
+    let config = Config::default();
+    let bounds = month_bounds(2025, &config);
+    let heading = quarter_heading(0, 3, &bounds, &config).unwrap();
+    assert_eq!(heading.len(), layout::grid_width(3, 21) + 1);
+    assert_eq!(heading.trim(), "Q1 (64 working days)");
+    // a row of March and April only heads the second quarter
+    let heading = quarter_heading(2, 2, &bounds, &config).unwrap();
+    assert!(heading.starts_with(&" ".repeat(22)));
+    assert_eq!(heading.trim(), "Q2 (65 working days)");
+    assert_eq!(quarter_heading(4, 2, &bounds, &config), None);
+
+    let fiscal = Config {
+        fiscal: Some(FiscalYear::new(4, FiscalLabel::Span)),
+        ..Config::default()
+    };
+    let bounds = month_bounds(2025, &fiscal);
+    let heading = quarter_heading(9, 3, &bounds, &fiscal).unwrap();
+    assert_eq!(heading.trim(), "Q4 (64 working days)");
+
+    // retail quarters group the periods of the retail year
+    let retail = Config {
+        retail: Some(RetailCalendar::default()),
+        ..Config::default()
+    };
+    let bounds = month_bounds(2023, &retail);
+    assert_eq!(bounds.iter().filter(|b| b.0 == 4).count(), 3);
+    let heading = quarter_heading(3, 3, &bounds, &retail).unwrap();
+    assert_eq!(heading.trim(), "Q2 (65 working days)");
+}
+
+#[test]
+fn test_half_heading() {
+    let config = Config::default();
+    let bounds = month_bounds(2025, &config);
+    let heading = half_heading(0, 3, &bounds, &config).unwrap();
+    assert_eq!(heading.len(), layout::grid_width(3, 21) + 1);
+    assert_eq!(heading.trim(), "H1 (129 working days)");
+    assert_eq!(half_heading(3, 3, &bounds, &config), None);
+    let heading = half_heading(4, 4, &bounds, &config).unwrap();
+    assert!(heading.starts_with(&" ".repeat(44)));
+    assert_eq!(heading.trim(), "H2 (132 working days)");
+
+    let fiscal = Config {
+        fiscal: Some(FiscalYear::new(4, FiscalLabel::Span)),
+        ..Config::default()
+    };
+    let bounds = month_bounds(2025, &fiscal);
+    assert_eq!(bounds[6].1, NaiveDate::from_ymd_opt(2025, 10, 1).unwrap());
+    let heading = half_heading(6, 6, &bounds, &fiscal).unwrap();
+    assert_eq!(heading.trim(), "H2 (130 working days)");
+}
+
+#[test]
+fn test_system_month_printable() {
+    let config = Config {
+        date_system: DateSystem::Hebrew,
+        ..Config::default()
+    };
+    let months = system_months(5786, &config).unwrap();
+    assert_eq!(months.len(), 12);
+    assert_eq!(
+        months[0].first,
+        NaiveDate::from_ymd_opt(2025, 9, 23).unwrap()
+    );
+    let locale_info = locale::LocaleInfo::new("en_US");
+    let printable = system_month_printable(
+        5786,
+        &months[0],
+        &locale_info.month_names(),
+        locale_info.week_day_names(),
+        &config,
+    );
+    assert_eq!(printable[0], "       Tishrei       ");
+    assert_eq!(printable[2], "        1  2  3  4  5");
+    assert_eq!(printable[6], " 27 28 29 30         ");
+    assert_eq!(system_months(5787, &config).unwrap().len(), 13);
+}
+
+#[test]
+fn test_persian_month_printable() {
+    let config = Config {
+        date_system: DateSystem::Persian,
+        locale: "fa_IR".to_string(),
+        starting_day: 6,
+        week_numbers: true,
+        ..Config::default()
+    };
+    let months = system_months(1404, &config).unwrap();
+    let locale_info = locale::LocaleInfo::new("fa_IR");
+    let printable = system_month_printable(
+        1404,
+        &months[0],
+        &locale_info.month_names(),
+        locale_info.week_day_names(),
+        &config,
+    );
+    assert_eq!(printable[0].trim(), "فروردین");
+    assert_eq!(printable[2], " 12                    1");
+    assert_eq!(printable[3], " 13  2  3  4  5  6  7  8");
+    assert_eq!(printable[7], " 16 30 31               ");
+}
+
+#[test]
+fn test_system_months_out_of_range() {
+    let config = Config {
+        date_system: DateSystem::Hebrew,
+        ..Config::default()
+    };
+    assert!(system_months(300000, &config).is_err());
+    assert!(calendar(300000, &config).is_err());
+}
+
+#[test]
+fn test_system_month_quarter() {
+    let config = Config {
+        date_system: DateSystem::Hebrew,
+        quarter: Some(2),
+        ..Config::default()
+    };
+    let rows = calendar(5786, &config).unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].len(), 3);
+    assert_eq!(rows[0][0][0].trim(), "Tevet");
+}
+
+#[test]
+fn test_decade_month_printable() {
+    let config = Config {
+        date_system: DateSystem::Republican,
+        ..Config::default()
+    };
+    let months = system_months(233, &config).unwrap();
+    assert_eq!(months.len(), 13);
+    assert_eq!(months[12].days, 5);
+    let locale_info = locale::LocaleInfo::new("en_US");
+    let printable = system_month_printable(
+        233,
+        &months[12],
+        &locale_info.month_names(),
+        republican::day_names(),
+        &config,
+    );
+    assert_eq!(printable[0], "        Sansculottides        ");
+    assert_eq!(printable[1], " Pr Du Tr Qa Qi Sx Sp Oc No Dé");
+    assert_eq!(printable[2], "  1  2  3  4  5               ");
+    assert_eq!(printable.len(), 2 + 4);
+    assert_eq!(month_width(&config), 30);
+}
+
+#[test]
+fn test_dual_day_label() {
+    let config = Config {
+        dual: Some(DateSystem::Hijri(HijriLeaps::Year16)),
+        ..Config::default()
+    };
+    // 2025-03-14 is 14 Ramadan 1446, and 2025-03-31 is 1 Shawwal
+    assert_eq!(day_label(2025, 14, 3, &[], &config), "14/14");
+    assert_eq!(day_label(2025, 31, 3, &[], &config), "31/1");
+    assert_eq!(cell_width(&config), 6);
+}
+
+#[test]
+fn test_secondary_months() {
+    let month_names = locale::LocaleInfo::new("en_US").month_names();
+    let config = Config {
+        dual: Some(DateSystem::Hijri(HijriLeaps::Year16)),
+        ..Config::default()
+    };
+    // March 2025 started in Ramadan 1446 and ended in Shawwal
+    assert_eq!(
+        month_title(2025, 3, &month_names, &config),
+        ["March (Ramadan/Shawwal)"]
+    );
+    assert_eq!(
+        month_title(2025, 2, &month_names, &config),
+        ["February (Shaban)"]
+    );
+    assert_eq!(
+        month_title(2025, 9, &month_names, &config),
+        ["September (Rabi al-Awwal/Rabi al-Thani)"]
+    );
+    // annotations that do not fit the width of a month start a new line
+    let config = Config {
+        show_jdn: true,
+        ..config
+    };
+    assert_eq!(
+        month_title(2025, 9, &month_names, &config),
+        ["September (Rabi al-Awwal/Rabi al-Thani)", "JD2460920"]
+    );
+    assert_eq!(
+        month_title(2025, 2, &month_names, &config),
+        ["February (Shaban) JD2460708"]
+    );
+    let config = Config {
+        lunar: true,
+        ..Config::default()
+    };
+    assert_eq!(
+        month_title(2025, 1, &month_names, &config),
+        ["January (M12/M1)"]
+    );
+    let config = Config {
+        date_system: DateSystem::Hebrew,
+        dual: Some(DateSystem::Gregorian),
+        ..Config::default()
+    };
+    let months = system_months(5786, &config).unwrap();
+    let printable = system_month_printable(
+        5786,
+        &months[0],
+        &month_names,
+        vec![String::new(); 7],
+        &config,
+    );
+    assert_eq!(printable[0].trim(), "Tishrei (September/October)");
+}
+
+#[test]
+fn test_maya_day_label() {
+    let config = Config {
+        maya: Some(GMT_CORRELATION),
+        ..Config::default()
+    };
+    // 2025-03-14 was 13.0.12.7.6 11 Kimi
+    assert_eq!(day_label(2025, 14, 3, &[], &config), "14/11");
+    assert_eq!(
+        month_title(2025, 3, &vec!["March".to_string(); 12], &config),
+        ["March 13.0.12.6.13"]
+    );
+    assert_eq!(cell_width(&config), 6);
+}
+
+#[test]
+fn test_lunar_day_label() {
+    let config = Config {
+        lunar: true,
+        ..Config::default()
+    };
+    // the Spring Festival 2025 was on 2025-01-29, and the leap sixth month started on 2025-07-25
+    assert_eq!(day_label(2025, 28, 1, &[], &config), "28/29");
+    assert_eq!(day_label(2025, 29, 1, &[], &config), "29/M1");
+    assert_eq!(day_label(2025, 25, 7, &[], &config), "25/L6");
+    assert_eq!(day_label(1900, 1, 1, &[], &config), "1");
+    assert_eq!(cell_width(&config), 7);
+    assert_eq!(
+        lunar_festival(NaiveDate::from_ymd_opt(2025, 10, 6).unwrap()),
+        Some("Mid-Autumn Festival")
+    );
 }
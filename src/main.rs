@@ -1,32 +1,209 @@
 use argh::FromArgs;
 use chrono::prelude::*;
+use chrono_tz::Tz;
 use locale_config::Locale;
 
-use rusti_cal::display;
+use rusti_cal::{
+    check_day_count, display, display_jdn, display_week, from_jdn, mjd_to_jdn, month_width,
+    parse_date, parse_month, parse_weekday, rata_die_to_jdn, to_jdn, today, ColorChoice, Columns,
+    Config, DateSystem, FiscalLabel, FiscalYear, Half, Highlights, IsoWeek, Quarter,
+    RetailCalendar, RetailPattern, WeekScheme, Weekend, YearNumbering, Ymd, GMT_CORRELATION,
+};
+use std::process;
 
 #[derive(FromArgs, PartialEq, Debug)]
 /// A command with positional arguments.
 struct WithPositional {
-    #[argh(positional, default = "default_year()")]
-    year: u32,
+    #[argh(positional)]
+    year: Option<u32>,
 
-    /// an optional starting_day which is "0" by default
-    #[argh(option, default = "0")]
-    starting_day: u32,
+    /// an optional starting_day which is "0" by default, or "6" with --calendar persian
+    #[argh(option)]
+    starting_day: Option<u32>,
 
-    /// an optional flag for enabling colored output
+    /// an optional flag for enabling colored output, the same as --color always
     #[argh(switch, short = 'c')]
-    color: bool,
+    always_color: bool,
+
+    /// when to use colored output: auto, always or never ("auto" by default)
+    #[argh(option)]
+    color: Option<ColorChoice>,
 
     /// an optional flag for enabling week numbers
     #[argh(switch, short = 'w')]
     week_numbers: bool,
+
+    /// weekend days as names or numbers, e.g. "fri,sat" (derived from the locale by default)
+    #[argh(option)]
+    weekend: Option<Weekend>,
+
+    /// dates or ranges to highlight, e.g. "2025-03-14,2025-07-01..2025-07-15"
+    #[argh(option)]
+    highlight: Vec<String>,
+
+    /// a file listing dates or ranges to highlight, one per line
+    #[argh(option)]
+    highlight_file: Vec<String>,
+
+    /// the style of highlighted dates, e.g. "black+on-cyan" ("black+on-green" by default)
+    #[argh(option)]
+    highlight_style: Option<String>,
+
+    /// the date to treat as today, e.g. "2025-03-14" (the system clock by default)
+    #[argh(option, from_str_fn(parse_date))]
+    today: Option<NaiveDate>,
+
+    /// an optional flag for disabling the highlighting of today
+    #[argh(switch)]
+    no_today: bool,
+
+    /// the IANA time zone used to determine today, e.g. "America/Sao_Paulo" (local time by default)
+    #[argh(option)]
+    tz: Option<Tz>,
+
+    /// months per row: 1, 2, 3, 4, 6, 12 or "auto" to fit the terminal ("3" by default)
+    #[argh(option, default = "Columns::Fixed(3)")]
+    columns: Columns,
+
+    /// an optional flag for printing weekdays as rows and weeks as columns, like ncal
+    #[argh(switch)]
+    vertical: bool,
+
+    /// an optional flag for showing the day of the year instead of the day of month
+    #[argh(switch, short = 'j')]
+    day_of_year: bool,
+
+    /// an optional flag for showing the Julian Day Number of the first day of each month
+    #[argh(switch)]
+    show_jdn: bool,
+
+    /// an optional flag for ISO week numbers, labelled with their ISO year when it differs
+    #[argh(switch)]
+    iso_week: bool,
+
+    /// how weeks are numbered: iso, us, simple or custom[:<first day>[:<min days>]] ("iso" by default)
+    #[argh(option)]
+    week_scheme: Option<WeekScheme>,
+
+    /// the first month of a fiscal year to print instead of January to December, e.g. "april"
+    #[argh(option, from_str_fn(parse_month))]
+    fiscal_start: Option<u32>,
+
+    /// which years name a fiscal year: start, end or span, e.g. FY2025/26 ("span" by default)
+    #[argh(option, default = "FiscalLabel::Span")]
+    fiscal_label: FiscalLabel,
+
+    /// print a retail year of 4 and 5 week periods: 4-4-5, 4-5-4 or 5-4-4
+    #[argh(option)]
+    retail: Option<RetailPattern>,
+
+    /// the weekday ending retail weeks and years ("sat" by default)
+    #[argh(option, from_str_fn(parse_weekday))]
+    retail_end_day: Option<u32>,
+
+    /// an optional flag for heading each quarter with its working days
+    #[argh(switch)]
+    quarters: bool,
+
+    /// a quarter to print instead of a year, e.g. "2025Q3"
+    #[argh(option)]
+    quarter: Option<Quarter>,
+
+    /// an optional flag for heading each half-year with its working days
+    #[argh(switch)]
+    halves: bool,
+
+    /// a half-year to print instead of a year, e.g. "2025H1"
+    #[argh(option)]
+    half: Option<Half>,
+
+    /// the calendar to print: gregorian, julian, hebrew, hijri, hijri:<15|16|fatimid|habash>,
+    /// persian, ethiopian, coptic, indian or republican ("gregorian" by default)
+    #[argh(option, default = "DateSystem::Gregorian")]
+    calendar: DateSystem,
+
+    /// another calendar whose day of month is shown next to each day and whose months are
+    /// named in the month headings, e.g. "hijri", or "gregorian" with another --calendar
+    #[argh(option)]
+    dual: Option<DateSystem>,
+
+    /// an optional flag for annotating days with their Chinese lunar date, from 1900 to 2100
+    #[argh(switch)]
+    lunar: bool,
+
+    /// an optional flag for naming years by their Japanese era, e.g. "令和7年"
+    #[argh(switch)]
+    era: bool,
+
+    /// an optional flag for annotating days with their Maya Tzolk'in number and months with
+    /// the Long Count of their first day
+    #[argh(switch)]
+    maya: bool,
+
+    /// the JDN of the Maya creation date 13.0.0.0.0 for --maya (584283 by default, the GMT
+    /// correlation)
+    #[argh(option)]
+    correlation: Option<i64>,
+
+    /// how years are numbered on the command line and in the heading: common, buddhist or
+    /// minguo (derived from the locale by default)
+    #[argh(option)]
+    year_numbering: Option<YearNumbering>,
+
+    /// an ISO week to print instead of a year, e.g. "2025-W33"
+    #[argh(option)]
+    week: Option<IsoWeek>,
+
+    #[argh(subcommand)]
+    command: Option<Command>,
 }
 
-fn default_year() -> u32 {
-    let now = Local::now();
-    let (_, year) = now.year_ce();
-    year
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand)]
+enum Command {
+    Jdn(JdnCommand),
+}
+
+#[derive(FromArgs, PartialEq, Debug)]
+/// Convert a date to Julian Day Number, Modified Julian Day and Rata Die, or back.
+#[argh(subcommand, name = "jdn")]
+struct JdnCommand {
+    /// the date to convert, as YYYY-MM-DD
+    #[argh(positional)]
+    date: Option<Ymd>,
+
+    /// the calendar of the date: gregorian, julian, reform, hebrew, hijri, persian, ethiopian,
+    /// coptic, indian or republican ("gregorian" by default)
+    #[argh(option, default = "DateSystem::Gregorian")]
+    calendar: DateSystem,
+
+    /// a Julian Day Number to convert
+    #[argh(option)]
+    jdn: Option<i64>,
+
+    /// a Modified Julian Day to convert
+    #[argh(option)]
+    mjd: Option<i64>,
+
+    /// a Rata Die day count to convert
+    #[argh(option)]
+    rd: Option<i64>,
+
+    /// the JDN of the Maya creation date 13.0.0.0.0 (584283 by default, the GMT correlation)
+    #[argh(option, default = "GMT_CORRELATION")]
+    correlation: i64,
+}
+
+fn run_jdn(cmd: JdnCommand) -> Result<(), String> {
+    let jdn = match (cmd.date, cmd.jdn, cmd.mjd, cmd.rd) {
+        (Some(date), None, None, None) => check_day_count(to_jdn(date, cmd.calendar)?)?,
+        (None, Some(jdn), None, None) => check_day_count(jdn)?,
+        (None, None, Some(mjd), None) => mjd_to_jdn(check_day_count(mjd)?),
+        (None, None, None, Some(rd)) => rata_die_to_jdn(check_day_count(rd)?),
+        _ => return Err("expected exactly one of a date, --jdn, --mjd or --rd".to_string()),
+    };
+    display_jdn(jdn, check_day_count(cmd.correlation)?);
+    Ok(())
 }
 
 fn locale() -> String {
@@ -38,13 +215,193 @@ fn locale() -> String {
         .unwrap_or_default()
 }
 
+fn highlights(arg: &WithPositional) -> Result<Highlights, String> {
+    let mut highlights = Highlights::default();
+    for list in &arg.highlight {
+        highlights.add(list)?;
+    }
+    for path in &arg.highlight_file {
+        let content =
+            std::fs::read_to_string(path).map_err(|e| format!("cannot read {}: {}", path, e))?;
+        highlights
+            .add_lines(&content)
+            .map_err(|e| format!("{}: {}", path, e))?;
+    }
+    if let Some(style) = &arg.highlight_style {
+        highlights.set_style(style)?;
+    }
+    Ok(highlights)
+}
+
+fn run(arg: WithPositional) -> Result<(), String> {
+    if let Some(Command::Jdn(cmd)) = arg.command {
+        return run_jdn(cmd);
+    }
+    // today is only resolved when it is highlighted or gives the default year
+    let tz = arg.tz;
+    let given_today = arg.today;
+    let current = || given_today.map_or_else(|| today(tz), Ok);
+    let color = match (arg.always_color, arg.color) {
+        (true, Some(_)) => return Err("-c cannot be combined with --color".to_string()),
+        (true, None) => ColorChoice::Always,
+        (false, color) => color.unwrap_or_default(),
+    };
+    let week_scheme = arg.week_scheme.unwrap_or_default();
+    if arg.iso_week && week_scheme != WeekScheme::Iso {
+        return Err("--iso-week cannot be combined with another --week-scheme".to_string());
+    }
+    let fiscal = arg
+        .fiscal_start
+        .map(|month| FiscalYear::new(month, arg.fiscal_label));
+    if fiscal.is_some() && (arg.iso_week || arg.week_scheme.is_some()) {
+        return Err(
+            "fiscal years number their own weeks, drop --iso-week and --week-scheme".to_string(),
+        );
+    }
+    let retail = match (arg.retail, arg.retail_end_day) {
+        (Some(pattern), end_day) => Some(RetailCalendar::new(pattern, end_day.unwrap_or(6))),
+        (None, Some(_)) => return Err("--retail-end-day needs --retail".to_string()),
+        (None, None) => None,
+    };
+    if retail.is_some()
+        && (fiscal.is_some() || arg.vertical || arg.week_scheme.is_some() || arg.iso_week)
+    {
+        return Err("--retail cannot be combined with --fiscal-start, --vertical, --week-scheme or --iso-week".to_string());
+    }
+    if retail.is_some() && arg.starting_day.is_some() {
+        return Err("retail weeks start after --retail-end-day, drop --starting-day".to_string());
+    }
+    if [
+        arg.year.is_some(),
+        arg.quarter.is_some(),
+        arg.half.is_some(),
+    ]
+    .iter()
+    .filter(|&&given| given)
+    .count()
+        > 1
+    {
+        return Err("expected either a year, --quarter or --half".to_string());
+    }
+    let system_months = arg.calendar != DateSystem::Gregorian;
+    if system_months
+        && (fiscal.is_some()
+            || retail.is_some()
+            || arg.vertical
+            || arg.quarters
+            || arg.quarter.is_some()
+            || arg.halves
+            || arg.half.is_some())
+    {
+        return Err(format!(
+            "--calendar {} cannot be combined with --fiscal-start, --retail, --vertical, quarters or halves",
+            arg.calendar
+        ));
+    }
+    // week numbers count 7-day weeks
+    if arg.calendar.week_length() != 7
+        && (arg.week_numbers || arg.iso_week || arg.week_scheme.is_some())
+    {
+        return Err(format!(
+            "--calendar {} has no week numbers, its weeks have {} days",
+            arg.calendar,
+            arg.calendar.week_length()
+        ));
+    }
+    if arg.dual.is_some() && retail.is_some() {
+        return Err("--dual cannot be combined with --retail".to_string());
+    }
+    if arg.era && (system_months || retail.is_some()) {
+        return Err("--era needs a gregorian --calendar without --retail".to_string());
+    }
+    if arg.lunar && (arg.dual.is_some() || system_months || retail.is_some()) {
+        return Err("--lunar needs a gregorian --calendar without --dual or --retail".to_string());
+    }
+    if arg.maya && (arg.dual.is_some() || arg.lunar || retail.is_some()) {
+        return Err("--maya cannot be combined with --dual, --lunar or --retail".to_string());
+    }
+    if arg.correlation.is_some() && !arg.maya {
+        return Err("--correlation needs --maya".to_string());
+    }
+    let correlation = check_day_count(arg.correlation.unwrap_or(GMT_CORRELATION))?;
+    let locale = locale();
+    // alternative calendars number their own years
+    let year_numbering = match arg.year_numbering {
+        _ if system_months => YearNumbering::Common,
+        Some(year_numbering) => year_numbering,
+        None => YearNumbering::from_locale(&locale),
+    };
+    let mut config = Config::default();
+    config.starting_day = match retail {
+        Some(retail) => retail.start_day(),
+        // Iranian weeks run from Saturday to Friday
+        None if arg.calendar == DateSystem::Persian => arg.starting_day.unwrap_or(6),
+        None => arg.starting_day.unwrap_or(0),
+    };
+    config.color = color.enabled();
+    config.week_numbers = arg.week_numbers || arg.iso_week || arg.week_scheme.is_some();
+    config.weekend = arg.weekend.unwrap_or_else(|| match arg.calendar {
+        DateSystem::Persian => Weekend::from_days(&[5]),
+        _ => Weekend::from_locale(&locale),
+    });
+    config.highlights = highlights(&arg)?;
+    config.today = if arg.no_today { None } else { Some(current()?) };
+    config.vertical = arg.vertical;
+    config.day_of_year = arg.day_of_year;
+    config.show_jdn = arg.show_jdn;
+    config.iso_week = arg.iso_week;
+    config.week_scheme = week_scheme;
+    config.fiscal = fiscal;
+    config.retail = retail;
+    // retail periods are always grouped into quarters
+    config.quarters = arg.quarters || arg.quarter.is_some() || retail.is_some();
+    config.quarter = arg.quarter.map(|quarter| quarter.quarter);
+    config.halves = arg.halves || arg.half.is_some();
+    config.half = arg.half.map(|half| half.half);
+    config.date_system = arg.calendar;
+    config.dual = arg.dual;
+    config.lunar = arg.lunar;
+    config.era = arg.era;
+    config.year_numbering = year_numbering;
+    config.maya = arg.maya.then_some(correlation);
+    config.locale = locale;
+    config.columns = arg.columns.resolve(month_width(&config));
+    match arg.week {
+        Some(week) => display_week(week, &config),
+        None => {
+            let year = match arg
+                .quarter
+                .map(|quarter| quarter.year)
+                .or(arg.half.map(|half| half.year))
+                .or(arg.year)
+            {
+                Some(year) => year_numbering.to_common(year)?,
+                None => {
+                    let today = config.today.map_or_else(current, Ok)?;
+                    match (retail, fiscal) {
+                        (Some(retail), _) => retail.year_of(today) as u32,
+                        (None, Some(fiscal)) => fiscal.year_of(today),
+                        (None, None) if system_months => {
+                            let jdn = to_jdn(
+                                Ymd::new(today.year() as i64, today.month(), today.day()),
+                                DateSystem::Gregorian,
+                            )?;
+                            from_jdn(jdn, arg.calendar).year as u32
+                        }
+                        (None, None) => today.year_ce().1,
+                    }
+                }
+            };
+            display(year, &config)?
+        }
+    }
+    Ok(())
+}
+
 fn main() {
     let arg = argh::from_env::<WithPositional>();
-    display(
-        arg.year,
-        &locale(),
-        arg.starting_day,
-        !arg.color,
-        arg.week_numbers,
-    );
+    if let Err(e) = run(arg) {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
 }
@@ -0,0 +1,135 @@
+// The Maya calendars: the Long Count of days since the creation date 13.0.0.0.0
+// 4 Ajaw 8 Kumk'u, the 260-day Tzolk'in of 13 numbers and 20 day signs, and the
+// 365-day Haab' of 18 months of 20 days and the 5 days of Wayeb'. Which day of our
+// calendars was the creation date is given by a correlation constant, its JDN.
+
+/// The Goodman-Martinez-Thompson correlation, under which the creation date was
+/// 3114-08-11 BC Gregorian.
+pub const GMT_CORRELATION: i64 = 584283;
+
+/// The days of a baktun, katun, tun, winal and kin.
+const PERIODS: [i64; 5] = [144000, 7200, 360, 20, 1];
+
+static DAY_SIGNS: [&str; 20] = [
+    "Imix", "Ik'", "Ak'bal", "K'an", "Chikchan", "Kimi", "Manik'", "Lamat", "Muluk", "Ok",
+    "Chuwen", "Eb", "Ben", "Ix", "Men", "K'ib", "Kaban", "Etz'nab", "Kawak", "Ajaw",
+];
+
+static HAAB_MONTHS: [&str; 19] = [
+    "Pop", "Wo'", "Sip", "Sotz'", "Sek", "Xul", "Yaxk'in", "Mol", "Ch'en", "Yax", "Sak'", "Keh",
+    "Mak", "K'ank'in", "Muwan", "Pax", "K'ayab", "Kumk'u", "Wayeb'",
+];
+
+/// A day of the Maya calendars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MayaDate {
+    /// the baktun, katun, tun, winal and kin of the Long Count
+    pub long_count: [i64; 5],
+    /// the Tzolk'in number, 1 to 13
+    pub tzolkin_number: u32,
+    /// the Tzolk'in day sign, 1 for Imix to 20 for Ajaw
+    pub tzolkin_sign: u32,
+    /// the day of the Haab' month, 0 to 19
+    pub haab_day: u32,
+    /// the Haab' month, 1 for Pop to 19 for Wayeb'
+    pub haab_month: u32,
+}
+
+impl MayaDate {
+    /// The Maya date of a JDN under `correlation`, the JDN of 13.0.0.0.0.
+    pub fn from_jdn(jdn: i64, correlation: i64) -> MayaDate {
+        let days = jdn - correlation;
+        let mut long_count = [days.div_euclid(PERIODS[0]), 0, 0, 0, 0];
+        for i in 1..5 {
+            long_count[i] = days.rem_euclid(PERIODS[i - 1]) / PERIODS[i];
+        }
+        // 13.0.0.0.0 was 4 Ajaw 8 Kumk'u
+        let haab = (days + 17 * 20 + 8).rem_euclid(365) as u32;
+        MayaDate {
+            long_count,
+            tzolkin_number: (days + 3).rem_euclid(13) as u32 + 1,
+            tzolkin_sign: (days + 19).rem_euclid(20) as u32 + 1,
+            haab_day: haab % 20,
+            haab_month: haab / 20 + 1,
+        }
+    }
+
+    /// The JDN of the day of the Long Count under `correlation`.
+    pub fn to_jdn(self, correlation: i64) -> i64 {
+        let days: i64 = self
+            .long_count
+            .iter()
+            .zip(PERIODS.iter())
+            .map(|(count, period)| count * period)
+            .sum();
+        correlation + days
+    }
+
+    /// The Tzolk'in day, e.g. `4 Ajaw`.
+    pub fn tzolkin_name(self) -> String {
+        format!(
+            "{} {}",
+            self.tzolkin_number,
+            DAY_SIGNS[self.tzolkin_sign as usize - 1]
+        )
+    }
+
+    /// The Haab' day, e.g. `8 Kumk'u`.
+    pub fn haab_name(self) -> String {
+        format!(
+            "{} {}",
+            self.haab_day,
+            HAAB_MONTHS[self.haab_month as usize - 1]
+        )
+    }
+}
+
+impl std::fmt::Display for MayaDate {
+    /// the Long Count, e.g. `13.0.12.7.6`
+    ///
+    /// The creation date closed the 13 baktuns of the previous world and is written
+    /// 13.0.0.0.0, while the days after it count from 0.0.0.0.1.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.long_count == [0; 5] {
+            return f.write_str("13.0.0.0.0");
+        }
+        let [baktun, katun, tun, winal, kin] = self.long_count;
+        write!(f, "{}.{}.{}.{}.{}", baktun, katun, tun, winal, kin)
+    }
+}
+
+#[test]
+fn test_maya_dates() {
+    // the creation date and the end of the 13th baktun, 2012-12-21
+    let creation = MayaDate::from_jdn(GMT_CORRELATION, GMT_CORRELATION);
+    assert_eq!(creation.to_string(), "13.0.0.0.0");
+    assert_eq!(creation.tzolkin_name(), "4 Ajaw");
+    assert_eq!(creation.haab_name(), "8 Kumk'u");
+    assert_eq!(
+        MayaDate::from_jdn(GMT_CORRELATION + 1, GMT_CORRELATION).to_string(),
+        "0.0.0.0.1"
+    );
+    let date = MayaDate::from_jdn(2456283, GMT_CORRELATION);
+    assert_eq!(date.to_string(), "13.0.0.0.0");
+    assert_eq!(date.tzolkin_name(), "4 Ajaw");
+    assert_eq!(date.haab_name(), "3 K'ank'in");
+    // 2025-03-14
+    let date = MayaDate::from_jdn(2460749, GMT_CORRELATION);
+    assert_eq!(date.to_string(), "13.0.12.7.6");
+    assert_eq!(date.tzolkin_name(), "11 Kimi");
+    assert_eq!(date.haab_name(), "9 Kumk'u");
+    // the Wayeb' days end the Haab' year
+    assert_eq!(
+        MayaDate::from_jdn(2456283 - 263 + 360, GMT_CORRELATION).haab_name(),
+        "0 Wayeb'"
+    );
+}
+
+#[test]
+fn test_maya_correlation() {
+    let gmt = MayaDate::from_jdn(2460749, GMT_CORRELATION);
+    let other = MayaDate::from_jdn(2460749 + 2, GMT_CORRELATION + 2);
+    assert_eq!(gmt, other);
+    assert_eq!(gmt.to_jdn(GMT_CORRELATION), 2460749);
+    assert_eq!(gmt.to_jdn(584285), 2460751);
+}
@@ -0,0 +1,147 @@
+// The arithmetic Solar Hijri (Jalali) calendar. The year starts at Nowruz with six
+// months of 31 days and five of 30, and Esfand has 29 days or, in leap years, 30.
+// Leap years follow the 33-year rule, which agrees with the astronomical calendar
+// of Iran from 1178 to 1634 AP.
+
+use crate::jdn::Ymd;
+
+/// JDN of 1 Farvardin AP 1 under the 33-year rule.
+const EPOCH: i64 = 1948320;
+
+const CYCLE_YEARS: i64 = 33;
+
+/// The leap years of each 33-year cycle.
+const CYCLE_LEAP_YEARS: i64 = 8;
+
+static MONTH_NAMES: [&str; 12] = [
+    "Farvardin",
+    "Ordibehesht",
+    "Khordad",
+    "Tir",
+    "Mordad",
+    "Shahrivar",
+    "Mehr",
+    "Aban",
+    "Azar",
+    "Dey",
+    "Bahman",
+    "Esfand",
+];
+
+static PERSIAN_MONTH_NAMES: [&str; 12] = [
+    "فروردین",
+    "اردیبهشت",
+    "خرداد",
+    "تیر",
+    "مرداد",
+    "شهریور",
+    "مهر",
+    "آبان",
+    "آذر",
+    "دی",
+    "بهمن",
+    "اسفند",
+];
+
+pub fn is_leap_year(year: i64) -> bool {
+    (25 * year + 11).rem_euclid(CYCLE_YEARS) < CYCLE_LEAP_YEARS
+}
+
+pub fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        1..=6 => 31,
+        12 if !is_leap_year(year) => 29,
+        _ => 30,
+    }
+}
+
+/// Days from the epoch to Nowruz of `year`.
+fn days_before_year(year: i64) -> i64 {
+    let years = year - 1;
+    365 * years + (CYCLE_LEAP_YEARS * years + 29).div_euclid(CYCLE_YEARS)
+}
+
+/// The name of a month, in Persian script for Persian locales.
+///
+/// The locale data only has the Gregorian month names, even for `fa_IR`, so the
+/// Solar Hijri names are kept here while weekday names come from the locale.
+pub fn month_name(month: u32, locale: &str) -> String {
+    let names = if locale.starts_with("fa") {
+        &PERSIAN_MONTH_NAMES
+    } else {
+        &MONTH_NAMES
+    };
+    names[(month as usize - 1) % 12].to_string()
+}
+
+pub fn to_jdn(date: Ymd) -> Result<i64, String> {
+    if date.month < 1 || date.month > 12 {
+        return Err(format!("invalid month in `{}`", date));
+    }
+    if date.day < 1 || date.day > days_in_month(date.year, date.month) {
+        return Err(format!("invalid day in `{}`", date));
+    }
+    let month = date.month as i64 - 1;
+    let days_before_month = 31 * month.min(6) + 30 * (month - 6).max(0);
+    Ok(EPOCH + days_before_year(date.year) + days_before_month + date.day as i64 - 1)
+}
+
+pub fn from_jdn(jdn: i64) -> Ymd {
+    let days = jdn - EPOCH;
+    let mut year = (days * CYCLE_YEARS).div_euclid(365 * CYCLE_YEARS + CYCLE_LEAP_YEARS) + 1;
+    while days_before_year(year) > days {
+        year -= 1;
+    }
+    while days_before_year(year + 1) <= days {
+        year += 1;
+    }
+    let day = (days - days_before_year(year)) as u32;
+    let (month, day) = if day < 186 {
+        (day / 31 + 1, day % 31 + 1)
+    } else {
+        ((day - 186) / 30 + 7, (day - 186) % 30 + 1)
+    };
+    Ymd::new(year, month, day)
+}
+
+#[test]
+fn test_persian_leap_years() {
+    let leap_years: Vec<i64> = (1395..=1412).filter(|&y| is_leap_year(y)).collect();
+    assert_eq!(leap_years, [1395, 1399, 1403, 1408, 1412]);
+    let cycle_days: i64 = (1..=33).map(|y| 365 + is_leap_year(y) as i64).sum();
+    assert_eq!(days_before_year(34), cycle_days);
+}
+
+#[test]
+fn test_persian_conversions() {
+    let test_cases = [
+        // Nowruz 1404, 2025-03-21
+        (Ymd::new(1404, 1, 1), 2460756),
+        // Nowruz 1403, 2024-03-20
+        (Ymd::new(1403, 1, 1), 2460390),
+        // 30 Esfand 1403, 2025-03-20
+        (Ymd::new(1403, 12, 30), 2460755),
+        // 1 Mehr 1404, 2025-09-23
+        (Ymd::new(1404, 7, 1), 2460942),
+        (Ymd::new(1, 1, 1), EPOCH),
+    ];
+    for (date, jdn) in test_cases.iter() {
+        assert_eq!(to_jdn(*date), Ok(*jdn), "{}", date);
+        assert_eq!(from_jdn(*jdn), *date, "{}", jdn);
+    }
+    assert!(to_jdn(Ymd::new(1404, 12, 30)).is_err());
+    assert!(to_jdn(Ymd::new(1404, 7, 31)).is_err());
+}
+
+#[test]
+fn test_persian_round_trip() {
+    for jdn in (2300000..2600000).step_by(31) {
+        assert_eq!(to_jdn(from_jdn(jdn)), Ok(jdn));
+    }
+}
+
+#[test]
+fn test_persian_month_names() {
+    assert_eq!(month_name(1, "en_US"), "Farvardin");
+    assert_eq!(month_name(12, "fa_IR"), "اسفند");
+}
@@ -0,0 +1,130 @@
+use std::str::FromStr;
+
+/// A quarter of a year, e.g. `2025Q3`. The year names a fiscal or retail year
+/// when one is printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quarter {
+    pub year: u32,
+    /// 1 to 4
+    pub quarter: u32,
+}
+
+/// A half of a year, e.g. `2025H1`. The year names a fiscal or retail year when one
+/// is printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Half {
+    pub year: u32,
+    /// 1 or 2
+    pub half: u32,
+}
+
+/// Parses `YYYY<letter>n` or `YYYY-<letter>n`, where n is 1 to `count`.
+fn parse_part(s: &str, letter: char, count: u32, what: &str) -> Result<(u32, u32), String> {
+    let invalid = || {
+        format!(
+            "invalid {} `{}`, expected YYYY{}1 to YYYY{}{}",
+            what, s, letter, letter, count
+        )
+    };
+    let (year, part) = s
+        .to_uppercase()
+        .split_once(letter)
+        .map(|(y, p)| (y.trim_end_matches('-').to_string(), p.to_string()))
+        .ok_or_else(invalid)?;
+    let year = year.parse::<u32>().map_err(|_| invalid())?;
+    let part = part
+        .parse::<u32>()
+        .ok()
+        .filter(|p| (1..=count).contains(p))
+        .ok_or_else(invalid)?;
+    Ok((year, part))
+}
+
+impl FromStr for Quarter {
+    type Err = String;
+
+    /// Parses `YYYYQq` or `YYYY-Qq`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, quarter) = parse_part(s, 'Q', 4, "quarter")?;
+        Ok(Quarter { year, quarter })
+    }
+}
+
+impl FromStr for Half {
+    type Err = String;
+
+    /// Parses `YYYYHh` or `YYYY-Hh`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, half) = parse_part(s, 'H', 2, "half")?;
+        Ok(Half { year, half })
+    }
+}
+
+impl std::fmt::Display for Quarter {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}Q{}", self.year, self.quarter)
+    }
+}
+
+impl std::fmt::Display for Half {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}H{}", self.year, self.half)
+    }
+}
+
+#[test]
+fn test_parse_quarter() {
+    assert_eq!(
+        "2025Q3".parse(),
+        Ok(Quarter {
+            year: 2025,
+            quarter: 3
+        })
+    );
+    assert_eq!(
+        "2025-q1".parse(),
+        Ok(Quarter {
+            year: 2025,
+            quarter: 1
+        })
+    );
+    assert!("2025Q5".parse::<Quarter>().is_err());
+    assert!("2025Q0".parse::<Quarter>().is_err());
+    assert!("2025-3".parse::<Quarter>().is_err());
+    assert_eq!(
+        Quarter {
+            year: 2025,
+            quarter: 3
+        }
+        .to_string(),
+        "2025Q3"
+    );
+}
+
+#[test]
+fn test_parse_half() {
+    assert_eq!(
+        "2025H2".parse(),
+        Ok(Half {
+            year: 2025,
+            half: 2
+        })
+    );
+    assert_eq!(
+        "2025-h1".parse(),
+        Ok(Half {
+            year: 2025,
+            half: 1
+        })
+    );
+    assert!("2025H3".parse::<Half>().is_err());
+    assert!("2025Q1".parse::<Half>().is_err());
+    assert_eq!(
+        Half {
+            year: 2025,
+            half: 1
+        }
+        .to_string(),
+        "2025H1"
+    );
+}
@@ -0,0 +1,119 @@
+// The French Republican calendar: twelve months of three 10-day décades, followed by
+// five sansculottides, or six in leap years. Leap years are the years before a
+// Gregorian leap year, which matches the sextile years 3, 7 and 11 of its official use
+// and keeps 1 Vendémiaire at the autumn equinox.
+
+use crate::jdn::Ymd;
+
+/// JDN of 1 Vendémiaire An I, 1792-09-22.
+const EPOCH: i64 = 2375840;
+
+/// The days of a décade, which replaced the week.
+pub const DECADE_DAYS: u32 = 10;
+
+static MONTH_NAMES: [&str; 13] = [
+    "Vendémiaire",
+    "Brumaire",
+    "Frimaire",
+    "Nivôse",
+    "Pluviôse",
+    "Ventôse",
+    "Germinal",
+    "Floréal",
+    "Prairial",
+    "Messidor",
+    "Thermidor",
+    "Fructidor",
+    "Sansculottides",
+];
+
+/// primidi, duodi, tridi, quartidi, quintidi, sextidi, septidi, octidi, nonidi and décadi
+static DAY_NAMES: [&str; 10] = ["Pr", "Du", "Tr", "Qa", "Qi", "Sx", "Sp", "Oc", "No", "Dé"];
+
+pub fn is_leap_year(year: i64) -> bool {
+    let next = year + 1;
+    next.rem_euclid(4) == 0 && (next.rem_euclid(100) != 0 || next.rem_euclid(400) == 0)
+}
+
+pub fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        1..=12 => 30,
+        13 if is_leap_year(year) => 6,
+        13 => 5,
+        _ => 0,
+    }
+}
+
+/// Days from the epoch to 1 Vendémiaire of `year`.
+fn days_before_year(year: i64) -> i64 {
+    365 * (year - 1) + year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400)
+}
+
+pub fn month_name(month: u32) -> String {
+    MONTH_NAMES[(month as usize - 1) % 13].to_string()
+}
+
+/// The abbreviated names of the days of a décade, from primidi to décadi.
+pub fn day_names() -> Vec<String> {
+    DAY_NAMES.iter().map(|day| day.to_string()).collect()
+}
+
+pub fn to_jdn(date: Ymd) -> Result<i64, String> {
+    if date.month < 1 || date.month > 13 {
+        return Err(format!("invalid month in `{}`", date));
+    }
+    if date.day < 1 || date.day > days_in_month(date.year, date.month) {
+        return Err(format!("invalid day in `{}`", date));
+    }
+    Ok(EPOCH + days_before_year(date.year) + 30 * (date.month as i64 - 1) + date.day as i64 - 1)
+}
+
+pub fn from_jdn(jdn: i64) -> Ymd {
+    let days = jdn - EPOCH;
+    let mut year = (days * 400).div_euclid(146097) + 1;
+    while days_before_year(year) > days {
+        year -= 1;
+    }
+    while days_before_year(year + 1) <= days {
+        year += 1;
+    }
+    let day = (days - days_before_year(year)) as u32;
+    Ymd::new(year, day / 30 + 1, day % 30 + 1)
+}
+
+#[test]
+fn test_republican_leap_years() {
+    let leap_years: Vec<i64> = (1..=20).filter(|&y| is_leap_year(y)).collect();
+    assert_eq!(leap_years, [3, 7, 11, 15, 19]);
+    assert!(!is_leap_year(199));
+    assert_eq!(days_in_month(3, 13), 6);
+    assert_eq!(days_in_month(4, 13), 5);
+}
+
+#[test]
+fn test_republican_conversions() {
+    let test_cases = [
+        (Ymd::new(1, 1, 1), EPOCH),
+        // 18 Brumaire VIII, 1799-11-09
+        (Ymd::new(8, 2, 18), 2378444),
+        // 1 Vendémiaire XII, 1803-09-24, after the sextile year XI
+        (Ymd::new(12, 1, 1), 2379858),
+        // 10 Nivôse XIV, 1805-12-31, the last day of its official use
+        (Ymd::new(14, 4, 10), 2380687),
+        // 6 Sansculottides XI, 1803-09-23
+        (Ymd::new(11, 13, 6), 2379857),
+    ];
+    for (date, jdn) in test_cases.iter() {
+        assert_eq!(to_jdn(*date), Ok(*jdn), "{}", date);
+        assert_eq!(from_jdn(*jdn), *date, "{}", jdn);
+    }
+    assert!(to_jdn(Ymd::new(12, 13, 6)).is_err());
+    assert!(to_jdn(Ymd::new(12, 1, 31)).is_err());
+}
+
+#[test]
+fn test_republican_round_trip() {
+    for jdn in (2300000..2600000).step_by(37) {
+        assert_eq!(to_jdn(from_jdn(jdn)), Ok(jdn));
+    }
+}
@@ -0,0 +1,237 @@
+use chrono::{Datelike, Duration, NaiveDate};
+use std::str::FromStr;
+
+const PERIODS: usize = 12;
+
+/// How many weeks the three periods of each quarter have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RetailPattern {
+    P445,
+    /// the pattern of the NRF retail calendar
+    #[default]
+    P454,
+    P544,
+}
+
+impl RetailPattern {
+    fn weeks(self) -> [u32; 3] {
+        match self {
+            RetailPattern::P445 => [4, 4, 5],
+            RetailPattern::P454 => [4, 5, 4],
+            RetailPattern::P544 => [5, 4, 4],
+        }
+    }
+}
+
+impl FromStr for RetailPattern {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "4-4-5" => Ok(RetailPattern::P445),
+            "4-5-4" => Ok(RetailPattern::P454),
+            "5-4-4" => Ok(RetailPattern::P544),
+            _ => Err(format!(
+                "invalid retail pattern `{}`, expected 4-4-5, 4-5-4 or 5-4-4",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for RetailPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let [a, b, c] = self.weeks();
+        write!(f, "{}-{}-{}", a, b, c)
+    }
+}
+
+/// A 52/53-week retail year of twelve 4 or 5 week periods, following the NRF rule:
+/// the year ends on the `end_day` nearest January 31, and the 53rd week of long
+/// years is added to the last period. The year is named after the calendar year
+/// it starts in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetailCalendar {
+    pub pattern: RetailPattern,
+    /// the weekday ending each week, numbered like `--starting-day` (0 is Sunday)
+    pub end_day: u32,
+}
+
+impl Default for RetailCalendar {
+    fn default() -> Self {
+        RetailCalendar {
+            pattern: RetailPattern::default(),
+            end_day: 6,
+        }
+    }
+}
+
+/// A period of a retail year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetailPeriod {
+    /// 1 to 12
+    pub number: u32,
+    pub start: NaiveDate,
+    pub weeks: u32,
+    /// the retail week number of the first week
+    pub first_week: u32,
+}
+
+impl RetailPeriod {
+    pub fn quarter(&self) -> u32 {
+        (self.number - 1) / 3 + 1
+    }
+
+    /// The last day of the period.
+    pub fn end(&self) -> NaiveDate {
+        self.start + Duration::days(self.weeks as i64 * 7 - 1)
+    }
+
+    /// The day on weekday `x` of week `y` of the period, both counted from 0.
+    pub fn date_at(&self, x: u32, y: u32) -> Option<NaiveDate> {
+        (x < 7 && y < self.weeks).then(|| self.start + Duration::days((y * 7 + x) as i64))
+    }
+}
+
+impl RetailCalendar {
+    pub fn new(pattern: RetailPattern, end_day: u32) -> RetailCalendar {
+        RetailCalendar {
+            pattern,
+            end_day: end_day % 7,
+        }
+    }
+
+    /// The weekday starting each week.
+    pub fn start_day(self) -> u32 {
+        (self.end_day + 1) % 7
+    }
+
+    /// The last day of the retail year named `year`.
+    pub fn year_end(self, year: i32) -> Option<NaiveDate> {
+        let january_end = NaiveDate::from_ymd_opt(year + 1, 1, 31)?;
+        let diff = (january_end.weekday().num_days_from_sunday() + 7 - self.end_day) % 7;
+        Some(if diff <= 3 {
+            january_end - Duration::days(diff as i64)
+        } else {
+            january_end + Duration::days(7 - diff as i64)
+        })
+    }
+
+    /// The first day of the retail year named `year`.
+    pub fn year_start(self, year: i32) -> Option<NaiveDate> {
+        Some(self.year_end(year - 1)? + Duration::days(1))
+    }
+
+    /// The number of weeks of the retail year named `year`, 52 or 53.
+    pub fn weeks(self, year: i32) -> u32 {
+        match (self.year_start(year), self.year_end(year)) {
+            (Some(start), Some(end)) => ((end - start).num_days() + 1) as u32 / 7,
+            _ => 52,
+        }
+    }
+
+    /// The twelve periods of the retail year named `year`.
+    pub fn periods(self, year: i32) -> Vec<RetailPeriod> {
+        let mut periods = Vec::with_capacity(PERIODS);
+        let mut start = match self.year_start(year) {
+            Some(start) => start,
+            None => return periods,
+        };
+        let mut first_week = 1;
+        for number in 1..=PERIODS as u32 {
+            let mut weeks = self.pattern.weeks()[(number as usize - 1) % 3];
+            if number == PERIODS as u32 && self.weeks(year) == 53 {
+                weeks += 1;
+            }
+            periods.push(RetailPeriod {
+                number,
+                start,
+                weeks,
+                first_week,
+            });
+            start += Duration::days(weeks as i64 * 7);
+            first_week += weeks;
+        }
+        periods
+    }
+
+    /// The retail year holding `date`.
+    pub fn year_of(self, date: NaiveDate) -> i32 {
+        let year = date.year();
+        match self.year_start(year) {
+            Some(start) if date < start => year - 1,
+            _ => year,
+        }
+    }
+
+    /// The retail year, period and week of `date`.
+    pub fn week_of(self, date: NaiveDate) -> Option<(i32, u32, u32)> {
+        let year = self.year_of(date);
+        let start = self.year_start(year)?;
+        let week = (date - start).num_days() as u32 / 7 + 1;
+        let period = self
+            .periods(year)
+            .iter()
+            .find(|p| p.start <= date && date <= p.end())?
+            .number;
+        Some((year, period, week))
+    }
+}
+
+#[test]
+fn test_parse_retail_pattern() {
+    assert_eq!("4-4-5".parse(), Ok(RetailPattern::P445));
+    assert_eq!("5-4-4".parse(), Ok(RetailPattern::P544));
+    assert_eq!(RetailPattern::P454.to_string(), "4-5-4");
+    assert!("4-4-4".parse::<RetailPattern>().is_err());
+}
+
+#[test]
+fn test_retail_year_bounds() {
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+    let nrf = RetailCalendar::default();
+    // NRF fiscal 2023 was a 53-week year
+    assert_eq!(nrf.year_start(2023), date(2023, 1, 29));
+    assert_eq!(nrf.year_end(2023), date(2024, 2, 3));
+    assert_eq!(nrf.weeks(2023), 53);
+    assert_eq!(nrf.year_start(2024), date(2024, 2, 4));
+    assert_eq!(nrf.year_end(2024), date(2025, 2, 1));
+    assert_eq!(nrf.weeks(2024), 52);
+    assert_eq!(nrf.year_start(2025), date(2025, 2, 2));
+    assert_eq!(nrf.year_end(2025), date(2026, 1, 31));
+}
+
+#[test]
+fn test_retail_periods() {
+    let nrf = RetailCalendar::default();
+    let periods = nrf.periods(2023);
+    assert_eq!(periods.len(), 12);
+    let weeks: Vec<u32> = periods.iter().map(|p| p.weeks).collect();
+    assert_eq!(weeks, [4, 5, 4, 4, 5, 4, 4, 5, 4, 4, 5, 5]);
+    assert_eq!(
+        periods[1].start,
+        NaiveDate::from_ymd_opt(2023, 2, 26).unwrap()
+    );
+    assert_eq!(periods[1].first_week, 5);
+    assert_eq!(periods[11].first_week, 49);
+    assert_eq!(
+        periods[11].end(),
+        NaiveDate::from_ymd_opt(2024, 2, 3).unwrap()
+    );
+    assert_eq!(periods[3].quarter(), 2);
+
+    let periods = RetailCalendar::new(RetailPattern::P445, 6).periods(2024);
+    let weeks: Vec<u32> = periods.iter().map(|p| p.weeks).collect();
+    assert_eq!(weeks, [4, 4, 5, 4, 4, 5, 4, 4, 5, 4, 4, 5]);
+}
+
+#[test]
+fn test_retail_week_of() {
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let nrf = RetailCalendar::default();
+    assert_eq!(nrf.week_of(date(2025, 2, 2)), Some((2025, 1, 1)));
+    assert_eq!(nrf.week_of(date(2025, 3, 1)), Some((2025, 1, 4)));
+    assert_eq!(nrf.week_of(date(2025, 3, 2)), Some((2025, 2, 5)));
+    assert_eq!(nrf.week_of(date(2025, 2, 1)), Some((2024, 12, 52)));
+    assert_eq!(nrf.week_of(date(2024, 2, 3)), Some((2023, 12, 53)));
+}
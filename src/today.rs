@@ -0,0 +1,81 @@
+use crate::parse_date;
+use chrono::{DateTime, NaiveDate, Utc};
+use chrono_tz::Tz;
+use std::env;
+
+/// Determines the current date, honouring `RUSTI_CAL_TODAY` (`YYYY-MM-DD`) and then
+/// `SOURCE_DATE_EPOCH` (seconds since the Unix epoch) before the system clock.
+///
+/// Instants are converted to dates in `tz`, or in the local time zone when `None`.
+/// `SOURCE_DATE_EPOCH` falls back to UTC rather than the local time zone.
+pub fn today(tz: Option<Tz>) -> Result<NaiveDate, String> {
+    let overridden = from_env(
+        env::var("RUSTI_CAL_TODAY").ok().as_deref(),
+        env::var("SOURCE_DATE_EPOCH").ok().as_deref(),
+        tz,
+    )?;
+    Ok(overridden.unwrap_or_else(|| match tz {
+        Some(tz) => Utc::now().with_timezone(&tz).date_naive(),
+        None => chrono::Local::now().date_naive(),
+    }))
+}
+
+fn from_env(
+    rusti_cal_today: Option<&str>,
+    source_date_epoch: Option<&str>,
+    tz: Option<Tz>,
+) -> Result<Option<NaiveDate>, String> {
+    if let Some(date) = rusti_cal_today.filter(|v| !v.is_empty()) {
+        return parse_date(date)
+            .map(Some)
+            .map_err(|e| format!("RUSTI_CAL_TODAY: {}", e));
+    }
+    if let Some(epoch) = source_date_epoch.filter(|v| !v.is_empty()) {
+        return epoch
+            .trim()
+            .parse::<i64>()
+            .ok()
+            .and_then(|secs| DateTime::from_timestamp(secs, 0))
+            .map(|datetime| match tz {
+                Some(tz) => Some(datetime.with_timezone(&tz).date_naive()),
+                None => Some(datetime.date_naive()),
+            })
+            .ok_or_else(|| format!("SOURCE_DATE_EPOCH: invalid timestamp `{}`", epoch));
+    }
+    Ok(None)
+}
+
+#[test]
+fn test_today_from_env() {
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+    assert_eq!(from_env(None, None, None), Ok(None));
+    assert_eq!(from_env(Some(""), Some(""), None), Ok(None));
+    assert_eq!(
+        from_env(Some("2025-03-14"), None, None),
+        Ok(date(2025, 3, 14))
+    );
+    assert_eq!(
+        from_env(None, Some("1741910400"), None),
+        Ok(date(2025, 3, 14))
+    );
+    assert_eq!(
+        from_env(Some("2024-02-29"), Some("1741910400"), None),
+        Ok(date(2024, 2, 29))
+    );
+    assert!(from_env(Some("14/03/2025"), None, None).is_err());
+    assert!(from_env(None, Some("yesterday"), None).is_err());
+}
+
+#[test]
+fn test_today_from_env_in_time_zone() {
+    // 2025-03-14 01:30 UTC is still the 13th in São Paulo and already the 14th in Tokyo
+    let epoch = Some("1741915800");
+    assert_eq!(
+        from_env(None, epoch, Some(chrono_tz::America::Sao_Paulo)),
+        Ok(NaiveDate::from_ymd_opt(2025, 3, 13))
+    );
+    assert_eq!(
+        from_env(None, epoch, Some(chrono_tz::Asia::Tokyo)),
+        Ok(NaiveDate::from_ymd_opt(2025, 3, 14))
+    );
+}
@@ -0,0 +1,190 @@
+use crate::weekend::parse_weekday;
+use chrono::{Datelike, Duration, NaiveDate};
+use std::str::FromStr;
+
+/// How weeks are numbered in the week number column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WeekScheme {
+    /// ISO 8601: weeks start on Monday and week 1 contains the first Thursday
+    #[default]
+    Iso,
+    /// weeks start on Sunday and week 1 contains January 1
+    Us,
+    /// week 1 is January 1 to 7, week 2 is January 8 to 14, and so on
+    Simple,
+    /// weeks start on `first_day` (`--starting-day` when `None`) and week 1 is the
+    /// first week with at least `min_days` days in January
+    Custom {
+        first_day: Option<u32>,
+        min_days: u32,
+    },
+}
+
+impl WeekScheme {
+    /// The week-numbering year and week of a date. Weekdays are numbered like
+    /// `--starting-day` (0 is Sunday).
+    pub fn week_of(self, date: NaiveDate, starting_day: u32) -> (i32, u32) {
+        match self {
+            WeekScheme::Iso => week_of(date, 1, 4),
+            WeekScheme::Us => week_of(date, 0, 1),
+            WeekScheme::Simple => (date.year(), date.ordinal0() / 7 + 1),
+            WeekScheme::Custom {
+                first_day,
+                min_days,
+            } => week_of(date, first_day.unwrap_or(starting_day) % 7, min_days),
+        }
+    }
+
+    /// The weekday starting the scheme's weeks, numbered like `--starting-day`, or `None`
+    /// when weeks start on the weekday of January 1.
+    pub fn first_day(self, starting_day: u32) -> Option<u32> {
+        match self {
+            WeekScheme::Iso => Some(1),
+            WeekScheme::Us => Some(0),
+            WeekScheme::Simple => None,
+            WeekScheme::Custom { first_day, .. } => Some(first_day.unwrap_or(starting_day) % 7),
+        }
+    }
+}
+
+impl FromStr for WeekScheme {
+    type Err = String;
+
+    /// Parses `iso`, `us`, `simple` or `custom[:<first day>[:<min days>]]`, e.g. `custom:sat`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(':');
+        match parts.next().unwrap_or_default() {
+            "iso" | "us" | "simple" if s.contains(':') => {
+                Err(format!("invalid week scheme `{}`", s))
+            }
+            "iso" => Ok(WeekScheme::Iso),
+            "us" => Ok(WeekScheme::Us),
+            "simple" => Ok(WeekScheme::Simple),
+            "custom" => {
+                let first_day = parts.next().map(parse_weekday).transpose()?;
+                let min_days = match parts.next() {
+                    Some(n) => n
+                        .parse::<u32>()
+                        .ok()
+                        .filter(|n| (1..=7).contains(n))
+                        .ok_or_else(|| format!("invalid minimal days `{}`, expected 1 to 7", n))?,
+                    None => 1,
+                };
+                if parts.next().is_some() {
+                    return Err(format!("invalid week scheme `{}`", s));
+                }
+                Ok(WeekScheme::Custom {
+                    first_day,
+                    min_days,
+                })
+            }
+            _ => Err(format!(
+                "invalid week scheme `{}`, expected iso, us, simple or custom",
+                s
+            )),
+        }
+    }
+}
+
+/// The first day of week 1 of `year`, for weeks starting on `first_day`.
+fn first_week_start(year: i32, first_day: u32, min_days: u32) -> Option<NaiveDate> {
+    let january_first = NaiveDate::from_ymd_opt(year, 1, 1)?;
+    let offset = (january_first.weekday().num_days_from_sunday() + 7 - first_day) % 7;
+    let start = january_first - Duration::days(offset as i64);
+    Some(if 7 - offset >= min_days {
+        start
+    } else {
+        start + Duration::days(7)
+    })
+}
+
+// Weeks belong to the year holding week 1, so the last days of December may fall
+// into week 1 of the next year and the first days of January into the last week
+// of the previous year.
+fn week_of(date: NaiveDate, first_day: u32, min_days: u32) -> (i32, u32) {
+    let start = |year| first_week_start(year, first_day, min_days);
+    let year = date.year();
+    let week_year = if start(year).is_some_and(|s| date < s) {
+        year - 1
+    } else if start(year + 1).is_some_and(|s| date >= s) {
+        year + 1
+    } else {
+        year
+    };
+    let days = start(week_year).map_or(0, |s| (date - s).num_days());
+    (week_year, days as u32 / 7 + 1)
+}
+
+#[test]
+fn test_parse_week_scheme() {
+    assert_eq!("iso".parse(), Ok(WeekScheme::Iso));
+    assert_eq!("us".parse(), Ok(WeekScheme::Us));
+    assert_eq!(
+        "custom".parse(),
+        Ok(WeekScheme::Custom {
+            first_day: None,
+            min_days: 1
+        })
+    );
+    assert_eq!(
+        "custom:sat".parse(),
+        Ok(WeekScheme::Custom {
+            first_day: Some(6),
+            min_days: 1
+        })
+    );
+    assert_eq!(
+        "custom:mon:4".parse(),
+        Ok(WeekScheme::Custom {
+            first_day: Some(1),
+            min_days: 4
+        })
+    );
+    assert!("custom:sat:8".parse::<WeekScheme>().is_err());
+    assert!("us:1".parse::<WeekScheme>().is_err());
+    assert!("fiscal".parse::<WeekScheme>().is_err());
+}
+
+#[test]
+fn test_week_of() {
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    // 2025-01-01 is a Wednesday, 2026-01-01 a Thursday
+    assert_eq!(WeekScheme::Us.week_of(date(2025, 1, 1), 0), (2025, 1));
+    assert_eq!(WeekScheme::Us.week_of(date(2025, 1, 4), 0), (2025, 1));
+    assert_eq!(WeekScheme::Us.week_of(date(2025, 1, 5), 0), (2025, 2));
+    assert_eq!(WeekScheme::Us.week_of(date(2025, 12, 27), 0), (2025, 52));
+    assert_eq!(WeekScheme::Us.week_of(date(2025, 12, 28), 0), (2026, 1));
+    assert_eq!(WeekScheme::Simple.week_of(date(2025, 1, 7), 0), (2025, 1));
+    assert_eq!(WeekScheme::Simple.week_of(date(2025, 1, 8), 0), (2025, 2));
+    assert_eq!(
+        WeekScheme::Simple.week_of(date(2024, 12, 31), 0),
+        (2024, 53)
+    );
+    let saturday = WeekScheme::Custom {
+        first_day: Some(6),
+        min_days: 1,
+    };
+    assert_eq!(saturday.week_of(date(2025, 1, 3), 0), (2025, 1));
+    assert_eq!(saturday.week_of(date(2025, 1, 4), 0), (2025, 2));
+    let starting_day = WeekScheme::Custom {
+        first_day: None,
+        min_days: 1,
+    };
+    assert_eq!(starting_day.week_of(date(2025, 1, 4), 6), (2025, 2));
+    assert_eq!(starting_day.week_of(date(2025, 1, 4), 0), (2025, 1));
+}
+
+#[test]
+fn test_week_of_matches_iso() {
+    let iso = WeekScheme::Custom {
+        first_day: Some(1),
+        min_days: 4,
+    };
+    let mut date = NaiveDate::from_ymd_opt(1999, 12, 1).unwrap();
+    while date.year() < 2031 {
+        let expected = (date.iso_week().year(), date.iso_week().week());
+        assert_eq!(WeekScheme::Iso.week_of(date, 0), expected, "{}", date);
+        assert_eq!(iso.week_of(date, 0), expected, "{}", date);
+        date = date.succ_opt().unwrap();
+    }
+}
@@ -0,0 +1,154 @@
+use chrono::{Datelike, NaiveDate};
+use std::str::FromStr;
+
+static DAY_NAMES: [&str; 7] = [
+    "sunday",
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+];
+
+// Countries whose weekend differs from Saturday+Sunday, after CLDR's weekData.
+static FRIDAY_SATURDAY: [&str; 15] = [
+    "AE", "BH", "DZ", "EG", "IL", "IQ", "JO", "KW", "LY", "OM", "QA", "SA", "SD", "SY", "YE",
+];
+static THURSDAY_FRIDAY: [&str; 1] = ["AF"];
+static FRIDAY_ONLY: [&str; 1] = ["IR"];
+static SUNDAY_ONLY: [&str; 2] = ["IN", "UG"];
+
+/// The set of non-working weekdays, numbered like `--starting-day` (0 is Sunday).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Weekend {
+    days: [bool; 7],
+}
+
+impl Default for Weekend {
+    fn default() -> Self {
+        Weekend::from_days(&[6, 0])
+    }
+}
+
+impl Weekend {
+    pub fn from_days(days: &[u32]) -> Weekend {
+        let mut weekend = Weekend { days: [false; 7] };
+        for &day in days {
+            weekend.days[(day % 7) as usize] = true;
+        }
+        weekend
+    }
+
+    /// Picks the customary weekend for the territory of a locale such as `ar_SA.UTF-8`.
+    pub fn from_locale(locale_str: &str) -> Weekend {
+        let territory = locale_str
+            .split(['.', '@'])
+            .next()
+            .and_then(|l| l.split('_').nth(1))
+            .unwrap_or_default();
+
+        if FRIDAY_SATURDAY.contains(&territory) {
+            Weekend::from_days(&[5, 6])
+        } else if THURSDAY_FRIDAY.contains(&territory) {
+            Weekend::from_days(&[4, 5])
+        } else if FRIDAY_ONLY.contains(&territory) {
+            Weekend::from_days(&[5])
+        } else if SUNDAY_ONLY.contains(&territory) {
+            Weekend::from_days(&[0])
+        } else {
+            Weekend::default()
+        }
+    }
+
+    pub fn contains(&self, weekday: u32) -> bool {
+        self.days[(weekday % 7) as usize]
+    }
+
+    /// Whether `weekday` ends a run of weekend days, e.g. Sunday in a Saturday+Sunday weekend.
+    pub fn is_last_day(&self, weekday: u32) -> bool {
+        self.contains(weekday) && !self.contains(weekday + 1)
+    }
+
+    pub fn is_weekend(&self, date: NaiveDate) -> bool {
+        self.contains(date.weekday().num_days_from_sunday())
+    }
+
+    /// Counts the working days between `from` and `to`, both inclusive.
+    pub fn working_days(&self, from: NaiveDate, to: NaiveDate) -> u32 {
+        from.iter_days()
+            .take_while(|date| *date <= to)
+            .filter(|date| !self.is_weekend(*date))
+            .count() as u32
+    }
+}
+
+impl FromStr for Weekend {
+    type Err = String;
+
+    /// Parses a comma separated list of day names (`fri,sat`) or numbers (`5,6`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "none" {
+            return Ok(Weekend { days: [false; 7] });
+        }
+        let days = s
+            .split(',')
+            .map(parse_weekday)
+            .collect::<Result<Vec<u32>, String>>()?;
+        Ok(Weekend::from_days(&days))
+    }
+}
+
+/// Parses an English day name of at least 2 letters or a number, where 0 is Sunday.
+pub fn parse_weekday(s: &str) -> Result<u32, String> {
+    let day = s.trim().to_lowercase();
+    day.parse::<u32>()
+        .ok()
+        .filter(|d| *d < 7)
+        .or_else(|| {
+            DAY_NAMES
+                .iter()
+                .position(|name| day.len() >= 2 && name.starts_with(&day))
+                .map(|d| d as u32)
+        })
+        .ok_or_else(|| format!("invalid weekday `{}`", day))
+}
+
+#[test]
+fn test_weekend_from_locale() {
+    assert_eq!(Weekend::from_locale("en_US"), Weekend::from_days(&[0, 6]));
+    assert_eq!(
+        Weekend::from_locale("ar_SA.UTF-8"),
+        Weekend::from_days(&[5, 6])
+    );
+    assert_eq!(Weekend::from_locale("fa_IR"), Weekend::from_days(&[5]));
+    assert_eq!(Weekend::from_locale("hi_IN"), Weekend::from_days(&[0]));
+    assert_eq!(Weekend::from_locale(""), Weekend::default());
+}
+
+#[test]
+fn test_parse_weekend() {
+    assert_eq!("fri,sat".parse(), Ok(Weekend::from_days(&[5, 6])));
+    assert_eq!("Friday, Saturday".parse(), Ok(Weekend::from_days(&[5, 6])));
+    assert_eq!("0".parse(), Ok(Weekend::from_days(&[0])));
+    assert_eq!("none".parse(), Ok(Weekend::from_days(&[])));
+    assert!("s".parse::<Weekend>().is_err());
+    assert!("7".parse::<Weekend>().is_err());
+}
+
+#[test]
+fn test_weekend_last_day() {
+    let weekend = Weekend::default();
+    assert!(!weekend.is_last_day(6));
+    assert!(weekend.is_last_day(0));
+    assert!(!weekend.is_last_day(1));
+}
+
+#[test]
+fn test_working_days() {
+    let from = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+    let to = NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+    assert_eq!(Weekend::default().working_days(from, to), 21);
+    assert_eq!(Weekend::from_days(&[5, 6]).working_days(from, to), 22);
+    assert_eq!(Weekend::from_days(&[0]).working_days(from, to), 26);
+}
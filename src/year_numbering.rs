@@ -0,0 +1,114 @@
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+/// The Buddhist Era counts from 543 BC.
+const BUDDHIST_OFFSET: i64 = 543;
+
+/// Minguo 1 is 1912, the founding of the Republic of China.
+const MINGUO_OFFSET: i64 = -1911;
+
+/// How the years of the Gregorian calendar are numbered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum YearNumbering {
+    /// the common era, e.g. 2025
+    #[default]
+    Common,
+    /// the Thai Buddhist Era, e.g. 2568
+    Buddhist,
+    /// the Republic of China calendar used in Taiwan, e.g. 114
+    Minguo,
+}
+
+impl YearNumbering {
+    /// Buddhist Era years for Thai locales and Minguo years for Taiwanese ones.
+    pub fn from_locale(locale: &str) -> YearNumbering {
+        if locale.starts_with("th") {
+            YearNumbering::Buddhist
+        } else if locale.starts_with("zh_TW") {
+            YearNumbering::Minguo
+        } else {
+            YearNumbering::Common
+        }
+    }
+
+    fn offset(self) -> i64 {
+        match self {
+            YearNumbering::Common => 0,
+            YearNumbering::Buddhist => BUDDHIST_OFFSET,
+            YearNumbering::Minguo => MINGUO_OFFSET,
+        }
+    }
+
+    /// The common era year of a year in this numbering.
+    pub fn to_common(self, year: u32) -> Result<u32, String> {
+        u32::try_from(year as i64 - self.offset())
+            .ok()
+            .filter(|&common| common > 0)
+            .ok_or_else(|| format!("year {} is before the common era", year))
+    }
+
+    /// The number of a common era year in this numbering, e.g. 2568 for 2025 BE.
+    pub fn number(self, common: i64) -> i64 {
+        common + self.offset()
+    }
+
+    /// The year as printed above a year of the calendar, e.g. `2568 BE`, or `พ.ศ. 2568`
+    /// for Thai locales.
+    pub fn year_name(self, common: i64, locale: &str) -> String {
+        let year = self.number(common);
+        match self {
+            YearNumbering::Common => common.to_string(),
+            YearNumbering::Buddhist if locale.starts_with("th") => format!("พ.ศ. {}", year),
+            YearNumbering::Buddhist => format!("{} BE", year),
+            // years before 1912 count back from it
+            YearNumbering::Minguo if year < 1 && locale.starts_with("zh") => {
+                format!("民國前{}年", 1 - year)
+            }
+            YearNumbering::Minguo if locale.starts_with("zh") => format!("民國{}年", year),
+            YearNumbering::Minguo if year < 1 => format!("{} before Minguo", 1 - year),
+            YearNumbering::Minguo => format!("Minguo {}", year),
+        }
+    }
+}
+
+impl FromStr for YearNumbering {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "common" | "ce" => Ok(YearNumbering::Common),
+            "buddhist" | "be" => Ok(YearNumbering::Buddhist),
+            "minguo" | "roc" => Ok(YearNumbering::Minguo),
+            _ => Err(format!(
+                "invalid year numbering `{}`, expected common, buddhist or minguo",
+                s
+            )),
+        }
+    }
+}
+
+#[test]
+fn test_year_numbering_from_locale() {
+    assert_eq!(YearNumbering::from_locale("th_TH"), YearNumbering::Buddhist);
+    assert_eq!(YearNumbering::from_locale("zh_TW"), YearNumbering::Minguo);
+    assert_eq!(YearNumbering::from_locale("zh_CN"), YearNumbering::Common);
+    assert_eq!(YearNumbering::from_locale("en_US"), YearNumbering::Common);
+}
+
+#[test]
+fn test_year_numbering_conversions() {
+    assert_eq!(YearNumbering::Buddhist.to_common(2568), Ok(2025));
+    assert_eq!(YearNumbering::Minguo.to_common(114), Ok(2025));
+    assert_eq!(YearNumbering::Common.to_common(2025), Ok(2025));
+    assert!(YearNumbering::Buddhist.to_common(543).is_err());
+    assert_eq!(YearNumbering::Buddhist.number(2025), 2568);
+    assert_eq!(YearNumbering::Minguo.number(2025), 114);
+    assert_eq!(YearNumbering::Buddhist.year_name(2025, "en_US"), "2568 BE");
+    assert_eq!(
+        YearNumbering::Buddhist.year_name(2025, "th_TH"),
+        "พ.ศ. 2568"
+    );
+    assert_eq!(YearNumbering::Minguo.year_name(2025, "zh_TW"), "民國114年");
+    assert_eq!(YearNumbering::Minguo.year_name(1911, "zh_TW"), "民國前1年");
+    assert_eq!(YearNumbering::Minguo.year_name(2025, "en_US"), "Minguo 114");
+}
@@ -1,4 +1,5 @@
 use assert_cmd::Command;
+use predicates::prelude::*;
 use predicates::str::contains;
 
 #[test]
@@ -15,3 +16,779 @@ fn week_numbers_not_affected_by_starting_day() {
         .success()
         .stdout(contains("38 18 19 20 21 22 23 24"));
 }
+
+#[test]
+fn no_escape_codes_when_piped() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.arg("2025")
+        .env_remove("CLICOLOR_FORCE")
+        .assert()
+        .success()
+        .stdout(contains("\x1b[").not());
+}
+
+#[test]
+fn color_always_emits_escape_codes() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "--color", "always"])
+        .env("NO_COLOR", "1")
+        .assert()
+        .success()
+        .stdout(contains("\x1b["));
+}
+
+#[test]
+fn short_color_flag_enables_color() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "-c"])
+        .env("NO_COLOR", "1")
+        .assert()
+        .success()
+        .stdout(contains("\x1b["));
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "-c", "--color", "never"])
+        .assert()
+        .failure()
+        .stderr(contains("-c cannot be combined with --color"));
+}
+
+#[test]
+fn clicolor_force_enables_auto_color() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.arg("2025")
+        .env_remove("NO_COLOR")
+        .env("CLICOLOR_FORCE", "1")
+        .assert()
+        .success()
+        .stdout(contains("\x1b["));
+}
+
+#[test]
+fn weekend_colors_follow_weekend_option() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "--color", "always", "--weekend", "fri"])
+        .assert()
+        .success()
+        .stdout(contains("\x1b[1;31mF"))
+        .stdout(contains("\x1b[1;33mS").not());
+}
+
+#[test]
+fn highlights_dates_and_ranges() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "--color", "always"])
+        .args(["--highlight", "2025-03-14,2025-07-01..2025-07-15"])
+        .args(["--highlight-style", "blue+bold"])
+        .assert()
+        .success()
+        .stdout(contains("\x1b[1;34m14"))
+        .stdout(contains("\x1b[1;34m 1"))
+        .stdout(contains("\x1b[1;34m15"));
+}
+
+#[test]
+fn rejects_invalid_highlight() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "--highlight", "2025-02-30"])
+        .assert()
+        .failure()
+        .stderr(contains("invalid date"));
+}
+
+#[test]
+fn today_can_be_overridden() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["--color", "always", "--today", "2024-02-29"])
+        .assert()
+        .success()
+        .stdout(contains("2024"))
+        .stdout(contains("\x1b[48;2;200;200;200;30m29"));
+}
+
+#[test]
+fn today_is_read_from_environment() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["--color", "always"])
+        .env("SOURCE_DATE_EPOCH", "1741910400")
+        .env("RUSTI_CAL_TODAY", "2023-07-04")
+        .assert()
+        .success()
+        .stdout(contains("2023"))
+        .stdout(contains("\x1b[48;2;200;200;200;30m 4"));
+}
+
+#[test]
+fn today_highlighting_can_be_disabled() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["--color", "always", "--today", "2024-02-29", "--no-today"])
+        .assert()
+        .success()
+        .stdout(contains("\x1b[48;2;200;200;200;30m").not());
+}
+
+#[test]
+fn no_today_does_not_resolve_today() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "--no-today"])
+        .env("RUSTI_CAL_TODAY", "not a date")
+        .assert()
+        .success()
+        .stdout(contains("2025"));
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.arg("2025")
+        .env("RUSTI_CAL_TODAY", "not a date")
+        .assert()
+        .failure()
+        .stderr(contains("RUSTI_CAL_TODAY"));
+}
+
+#[test]
+fn today_follows_time_zone() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["--color", "always", "--tz", "America/Sao_Paulo"])
+        .env_remove("RUSTI_CAL_TODAY")
+        .env("SOURCE_DATE_EPOCH", "1735695000")
+        .assert()
+        .success()
+        .stdout(contains("2024"))
+        .stdout(contains("\x1b[48;2;200;200;200;30m31"));
+}
+
+#[test]
+fn prints_months_in_requested_columns() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "--columns", "4"])
+        .assert()
+        .success()
+        .stdout(contains("  March                 April"));
+}
+
+#[test]
+fn auto_columns_fit_the_width() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "--columns", "auto"])
+        .env("COLUMNS", "140")
+        .assert()
+        .success()
+        .stdout(contains("  May                   June"));
+}
+
+#[test]
+fn prints_vertical_layout() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "--vertical", "--starting-day", "1"])
+        .assert()
+        .success()
+        .stdout(contains("Mo     3 10 17 24 31"));
+}
+
+#[test]
+fn prints_day_of_year() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "-j", "-w"])
+        .assert()
+        .success()
+        .stdout(contains("      Su  Mo  Tu  We  Th  Fr  Sa"))
+        .stdout(contains(" 52 355 356 357 358 359 360 361"));
+}
+
+#[test]
+fn converts_date_to_julian_day_number() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["jdn", "2025-03-14"])
+        .assert()
+        .success()
+        .stdout(contains("JDN       2460749"))
+        .stdout(contains("MJD       60748"))
+        .stdout(contains("Rata Die  739324"));
+}
+
+#[test]
+fn converts_modified_julian_day_to_date() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["jdn", "--mjd", "0"])
+        .assert()
+        .success()
+        .stdout(contains("Gregorian 1858-11-17"));
+}
+
+#[test]
+fn shows_julian_day_number_of_months() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "--show-jdn"])
+        .assert()
+        .success()
+        .stdout(contains("January JD2460677"));
+}
+
+#[test]
+fn month_annotations_that_do_not_fit_start_a_new_line() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "--dual", "hijri", "--show-jdn", "--color", "never"])
+        .assert()
+        .success()
+        .stdout(contains("September (Rabi al-Awwal/Rabi al-Thani)"))
+        .stdout(contains("JD2460920"))
+        .stdout(contains("December (Jumada al-Akhira/Rajab)"))
+        .stdout(contains("JD2461011"));
+}
+
+#[test]
+fn iso_weeks_show_their_year_when_it_differs() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "--iso-week"])
+        .assert()
+        .success()
+        .stdout(contains("     W52 21 22 23 24 25 26 27"))
+        .stdout(contains("2026-W01 28 29 30 31"));
+}
+
+#[test]
+fn prints_a_single_iso_week() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["--week", "2025-W33"])
+        .assert()
+        .success()
+        .stdout(contains("2025-W33"))
+        .stdout(contains(" Mo Tu We Th Fr Sa Su"))
+        .stdout(contains(" 11 12 13 14 15 16 17"));
+}
+
+#[test]
+fn us_week_scheme_counts_from_january_first() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "--week-scheme", "us"])
+        .assert()
+        .success()
+        .stdout(contains("  1           1  2  3  4"))
+        .stdout(contains("  2  5  6  7  8  9 10 11"));
+}
+
+#[test]
+fn week_numbers_follow_most_of_a_line_starting_on_another_day() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "-w", "--week-scheme", "us", "--starting-day", "1"])
+        .assert()
+        .success()
+        .stdout(contains("  1        1  2  3  4  5"))
+        .stdout(contains("  2  6  7  8  9 10 11 12"));
+}
+
+#[test]
+fn custom_week_scheme_starts_weeks_on_the_given_day() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "--week-scheme", "custom:sat", "--starting-day", "6"])
+        .assert()
+        .success()
+        .stdout(contains("  2  4  5  6  7  8  9 10"));
+}
+
+#[test]
+fn fiscal_year_starts_at_the_given_month() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "--fiscal-start", "april", "-w"])
+        .assert()
+        .success()
+        .stdout(contains("FY2025/26"))
+        .stdout(predicate::str::is_match(r"(?s)April.*January.*March").unwrap())
+        .stdout(contains(" Q1 W01        1  2  3  4  5"))
+        .stdout(contains(" Q4 W53 29 30 31"));
+}
+
+#[test]
+fn fiscal_year_named_by_its_end() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2026", "--fiscal-start", "oct", "--fiscal-label", "end"])
+        .assert()
+        .success()
+        .stdout(contains("FY2026"))
+        .stdout(predicate::str::is_match(r"(?s)October.*September").unwrap());
+}
+
+#[test]
+fn retail_calendar_groups_weeks_into_periods() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2023", "--retail", "4-5-4", "-w"])
+        .assert()
+        .success()
+        .stdout(contains("FY2023 (4-5-4)"))
+        .stdout(contains("P1 January 29"))
+        .stdout(contains("  9 26 27 28 29 30 31  1"))
+        .stdout(contains(" 53 28 29 30 31  1  2  3"));
+}
+
+#[test]
+fn retail_periods_are_grouped_into_quarters() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2023", "--retail", "4-5-4", "--weekend", "sat,sun"])
+        .assert()
+        .success()
+        .stdout(contains("Q1 (65 working days)"))
+        .stdout(contains("Q4 (70 working days)"));
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2023", "--retail", "4-5-4", "--starting-day", "1"])
+        .assert()
+        .failure()
+        .stderr(contains("drop --starting-day"));
+}
+
+#[test]
+fn quarters_are_headed_with_working_days() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "--quarters", "--weekend", "sat,sun"])
+        .assert()
+        .success()
+        .stdout(contains("Q1 (64 working days)"))
+        .stdout(contains("Q4 (66 working days)"));
+}
+
+#[test]
+fn half_years_are_headed_with_working_days() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "--halves", "--quarters", "--weekend", "sat,sun"])
+        .assert()
+        .success()
+        .stdout(contains("H1 (129 working days)"))
+        .stdout(
+            predicate::str::is_match(r"H2 \(132 working days\) *\n *Q3 \(66 working days\)")
+                .unwrap(),
+        );
+}
+
+#[test]
+fn prints_a_single_half_of_a_fiscal_year() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["--half", "2025H2", "--fiscal-start", "april"])
+        .assert()
+        .success()
+        .stdout(contains("FY2025/26 H2"))
+        .stdout(predicate::str::is_match(r"(?s)October.*March").unwrap())
+        .stdout(contains("September").not());
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["--half", "2025H1", "--quarter", "2025Q1"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn prints_a_single_quarter() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["--quarter", "2025Q3"])
+        .assert()
+        .success()
+        .stdout(contains("2025 Q3"))
+        .stdout(contains("July"))
+        .stdout(contains("September"))
+        .stdout(contains("June").not())
+        .stdout(contains("October").not());
+}
+
+#[test]
+fn prints_a_hebrew_year() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["5787", "--calendar", "hebrew"])
+        .assert()
+        .success()
+        .stdout(contains("5787 AM"))
+        .stdout(predicate::str::is_match(r"(?s)Tishrei.*Adar I .*Adar II.*Elul").unwrap());
+}
+
+#[test]
+fn converts_hebrew_dates() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["jdn", "5785-01-15", "--calendar", "hebrew"])
+        .assert()
+        .success()
+        .stdout(contains("Gregorian 2025-04-13"))
+        .stdout(contains("Hebrew    5785-01-15 (15 Nisan 5785)"));
+}
+
+#[test]
+fn prints_a_hijri_year() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["1446", "--calendar", "hijri"])
+        .assert()
+        .success()
+        .stdout(contains("1446 AH"))
+        .stdout(predicate::str::is_match(r"(?s)Muharram.*Ramadan.*Dhu al-Hijjah").unwrap());
+}
+
+#[test]
+fn converts_hijri_dates() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["jdn", "1447-01-01", "--calendar", "hijri"])
+        .assert()
+        .success()
+        .stdout(contains("Gregorian 2025-06-27"))
+        .stdout(contains("Hijri     1447-01-01 (1 Muharram 1447)"));
+}
+
+#[test]
+fn dual_shows_the_day_of_another_calendar() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "--dual", "hijri", "--color", "never"])
+        .assert()
+        .success()
+        .stdout(contains(" 30/30  31/1"));
+}
+
+#[test]
+fn prints_a_persian_year_from_saturday() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["1404", "--calendar", "persian", "--color", "never"])
+        .assert()
+        .success()
+        .stdout(contains("1404 AP"))
+        .stdout(contains(" Sa Su Mo Tu We Th Fr "))
+        .stdout(predicate::str::is_match(r"(?s)Farvardin.*Esfand").unwrap());
+}
+
+#[test]
+fn converts_persian_dates() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["jdn", "1404-01-01", "--calendar", "persian"])
+        .assert()
+        .success()
+        .stdout(contains("Gregorian 2025-03-21"))
+        .stdout(contains("Persian   1404-01-01 (1 Farvardin 1404)"));
+}
+
+#[test]
+fn lunar_annotates_days_and_names_the_year() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "--lunar", "--color", "never"])
+        .assert()
+        .success()
+        .stdout(contains("2025 Yisi (Snake)"))
+        .stdout(contains("29/M1"))
+        .stdout(contains("25/L6"));
+}
+
+#[test]
+fn era_labels_the_months_of_an_era_change() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2019", "--era", "--color", "never"])
+        .assert()
+        .success()
+        .stdout(contains("2019 Heisei 31/Reiwa 1"))
+        .stdout(contains("April Heisei 31"))
+        .stdout(contains("May Reiwa 1"));
+}
+
+#[test]
+fn era_labels_months_with_every_era_of_their_days() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["1989", "--era", "--color", "never"])
+        .assert()
+        .success()
+        .stdout(contains("1989 Showa 64/Heisei 1"))
+        .stdout(contains("\n  Showa 64/Heisei 1 "))
+        .stdout(contains("February Heisei 1"));
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["1926", "--era", "--color", "never"])
+        .assert()
+        .success()
+        .stdout(contains("November Taisho 15"))
+        .stdout(contains("Taisho 15/Showa 1"));
+}
+
+#[test]
+fn era_names_the_year_in_japanese_locales() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "--era", "--color", "never"])
+        .env("LC_ALL", "ja_JP.UTF-8")
+        .assert()
+        .success()
+        .stdout(contains("2025 令和7年"));
+}
+
+#[test]
+fn converts_dates_to_japanese_eras() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["jdn", "2019-05-01"])
+        .assert()
+        .success()
+        .stdout(contains("Japanese  令和元年05月01日"));
+}
+
+#[test]
+fn buddhist_era_years_are_read_and_printed() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2568", "--year-numbering", "buddhist", "--color", "never"])
+        .assert()
+        .success()
+        .stdout(contains("2568 BE"))
+        // 2025-01-01 was a Wednesday
+        .stdout(contains("           1  2  3  4"));
+}
+
+#[test]
+fn fiscal_and_retail_years_follow_the_year_numbering() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args([
+        "2568",
+        "--year-numbering",
+        "buddhist",
+        "--fiscal-start",
+        "april",
+        "--color",
+        "never",
+    ])
+    .assert()
+    .success()
+    .stdout(contains("FY2568/69"));
+
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args([
+        "114",
+        "--year-numbering",
+        "minguo",
+        "--retail",
+        "4-4-5",
+        "--color",
+        "never",
+    ])
+    .assert()
+    .success()
+    .stdout(contains("FY114 (4-4-5)"));
+}
+
+#[test]
+fn minguo_years_default_from_taiwanese_locales() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["114", "--color", "never"])
+        .env("LC_ALL", "zh_TW.UTF-8")
+        .assert()
+        .success()
+        .stdout(contains("民國114年"));
+}
+
+#[test]
+fn prints_an_ethiopian_year_with_pagume() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2017", "--calendar", "ethiopian", "--color", "never"])
+        .assert()
+        .success()
+        .stdout(contains("2017 (Ethiopian)"))
+        .stdout(predicate::str::is_match(r"(?s)Meskerem.*Nehase.*Pagume").unwrap());
+}
+
+#[test]
+fn converts_coptic_dates() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["jdn", "1742-01-01", "--calendar", "coptic"])
+        .assert()
+        .success()
+        .stdout(contains("Gregorian 2025-09-11"))
+        .stdout(contains("Ethiopian 2018-01-01 (1 Meskerem 2018)"));
+}
+
+#[test]
+fn prints_an_indian_year_with_gregorian_days() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args([
+        "1947",
+        "--calendar",
+        "indian",
+        "--dual",
+        "gregorian",
+        "--color",
+        "never",
+    ])
+    .assert()
+    .success()
+    .stdout(contains("1947 Saka"))
+    .stdout(predicate::str::is_match(r"(?s)Chaitra.*1/22.*Phalguna").unwrap());
+}
+
+#[test]
+fn converts_indian_dates() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["jdn", "1947-01-01", "--calendar", "saka"])
+        .assert()
+        .success()
+        .stdout(contains("Gregorian 2025-03-22"))
+        .stdout(contains("Indian    1947-01-01 (1 Chaitra 1947)"));
+}
+
+#[test]
+fn prints_a_republican_year_in_decades() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["233", "--calendar", "republican", "--color", "never"])
+        .assert()
+        .success()
+        .stdout(contains("An 233"))
+        .stdout(contains(" Pr Du Tr Qa Qi Sx Sp Oc No Dé"))
+        .stdout(contains("  1  2  3  4  5  6  7  8  9 10"))
+        .stdout(predicate::str::is_match(r"(?s)Vendémiaire.*Fructidor.*Sansculottides").unwrap());
+}
+
+#[test]
+fn converts_republican_dates() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["jdn", "0008-02-18", "--calendar", "french"])
+        .assert()
+        .success()
+        .stdout(contains("Gregorian 1799-11-09"))
+        .stdout(contains("French    0008-02-18 (18 Brumaire 8)"));
+}
+
+#[test]
+fn maya_annotates_days_and_months() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "--maya", "--color", "never"])
+        .assert()
+        .success()
+        .stdout(contains("March 13.0.12.6.13"))
+        .stdout(contains("14/11"));
+}
+
+#[test]
+fn converts_dates_to_maya_dates() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["jdn", "2012-12-21"])
+        .assert()
+        .success()
+        .stdout(contains("Maya      13.0.0.0.0 (4 Ajaw 3 K'ank'in)"));
+
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["jdn", "2012-12-23", "--correlation", "584285"])
+        .assert()
+        .success()
+        .stdout(contains("Maya      13.0.0.0.0 (4 Ajaw 3 K'ank'in)"));
+}
+
+#[test]
+fn maya_creation_date_is_written_with_13_baktuns() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["jdn", "--jdn", "584283"])
+        .assert()
+        .success()
+        .stdout(contains("Maya      13.0.0.0.0 (4 Ajaw 8 Kumk'u)"));
+}
+
+#[test]
+fn correlation_needs_maya() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "--correlation", "584285"])
+        .assert()
+        .failure()
+        .stderr(contains("--correlation needs --maya"));
+
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args([
+        "2025",
+        "--maya",
+        "--correlation",
+        "584285",
+        "--color",
+        "never",
+    ])
+    .assert()
+    .success()
+    .stdout(contains("March 13.0.12.6.11"));
+}
+
+#[test]
+fn rejects_out_of_range_correlations() {
+    for args in [
+        &["2025", "--maya", "--correlation", "-9223372036854775808"][..],
+        &["jdn", "--jdn", "0", "--correlation", "-9223372036854775808"][..],
+    ]
+    .iter()
+    {
+        let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+        cmd.args(*args)
+            .assert()
+            .failure()
+            .stderr(contains("out of range"));
+    }
+}
+
+#[test]
+fn dual_names_the_secondary_months_in_headings() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["2025", "--dual", "hebrew", "--color", "never"])
+        .assert()
+        .success()
+        .stdout(contains("September (Elul/Tishrei)"));
+
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args([
+        "1404",
+        "--calendar",
+        "persian",
+        "--dual",
+        "gregorian",
+        "--color",
+        "never",
+    ])
+    .assert()
+    .success()
+    .stdout(contains("Farvardin (March/April)"))
+    .stdout(contains("1/21"));
+}
+
+#[test]
+fn rejects_out_of_range_day_counts() {
+    for args in [
+        &["jdn", "--jdn", "9223372036854775807"][..],
+        &["jdn", "--jdn", "1000000000000000"],
+        &["jdn", "99999999999999-07-01", "--calendar", "hebrew"],
+        &["jdn", "--rd", "9223372036854775000"],
+        &["jdn", "--mjd", "-9223372036854775000"],
+        &["jdn", "9000000000-01-01", "--calendar", "julian"],
+    ] {
+        let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+        cmd.args(args)
+            .assert()
+            .failure()
+            .stderr(contains("out of range"));
+    }
+}
+
+#[test]
+fn errors_name_the_calendar_as_given() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["1446", "--calendar", "hijri:habash", "--quarters"])
+        .assert()
+        .failure()
+        .stderr(contains("--calendar hijri:habash cannot be combined"));
+}
+
+#[test]
+fn rejects_calendar_years_beyond_the_printable_days() {
+    for args in [
+        ["300000", "--calendar", "hebrew"],
+        ["270000", "--calendar", "hijri"],
+        ["262143", "--calendar", "persian"],
+    ]
+    .iter()
+    {
+        let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+        cmd.args(args)
+            .assert()
+            .failure()
+            .stderr(contains("is out of range, its days cannot be printed"));
+    }
+}
+
+#[test]
+fn reform_years_are_not_printed() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["1582", "--calendar", "reform"])
+        .assert()
+        .failure()
+        .stderr(contains("--calendar reform only converts dates"));
+}
+
+#[test]
+fn decades_have_no_week_numbers() {
+    let mut cmd = Command::cargo_bin("rusti-cal").unwrap();
+    cmd.args(["233", "--calendar", "french", "-w"])
+        .assert()
+        .failure()
+        .stderr(contains(
+            "--calendar republican has no week numbers, its weeks have 10 days",
+        ));
+}